@@ -0,0 +1,208 @@
+//! # Bulk Dataset Files
+//! Readers for the gzipped JSONL shards published by the Datasets API
+//! (see [`SemanticScholar::get_dataset`](crate::SemanticScholar::get_dataset)).
+//!
+//! Records are decoded lazily, one line at a time, so a shard never has to fit in memory.
+//! Dataset records use lowercase keys (`corpusid`, `citationcount`, ...) where the Graph API
+//! uses camelCase (`corpusId`, `citationCount`, ...); [`Paper`] and [`Author`] accept both.
+//!
+//! | Dataset | Record |
+//! | --- | --- |
+//! | `papers` | [`Paper`] |
+//! | `abstracts` | [`Paper`] (`corpus_id` and `abstract_text` only) |
+//! | `authors` | [`Author`] |
+//! | `citations` | [`CitationRecord`] |
+//! | `tldrs` | [`TldrRecord`] |
+//!
+//! Diffs between releases can be applied to a local copy with the [`store`] module.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use anyhow::Result;
+//! # fn main() -> Result<()> {
+//! for paper in ss_tools::datasets::read_papers("papers/part-0.jsonl.gz")? {
+//!     let paper = paper?;
+//!     println!("{:?}: {:?}", paper.corpus_id, paper.title);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod store;
+
+use crate::error::{Error, Result};
+use crate::structs::{Author, CorpusId, Paper};
+use flate2::read::MultiGzDecoder;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// An edge of the `citations` dataset: `citing_corpus_id` cites `cited_corpus_id`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CitationRecord {
+    #[serde(
+        rename = "citationid",
+        alias = "citationId",
+        default = "Option::default"
+    )]
+    pub citation_id: Option<u64>,
+    #[serde(
+        rename = "citingcorpusid",
+        alias = "citingCorpusId",
+        default = "Option::default"
+    )]
+    pub citing_corpus_id: Option<CorpusId>,
+    /// `None` when the cited paper could not be resolved to a corpus id.
+    #[serde(
+        rename = "citedcorpusid",
+        alias = "citedCorpusId",
+        default = "Option::default"
+    )]
+    pub cited_corpus_id: Option<CorpusId>,
+    #[serde(
+        rename = "isinfluential",
+        alias = "isInfluential",
+        default = "Option::default"
+    )]
+    pub is_influential: Option<bool>,
+    #[serde(default = "Option::default")]
+    pub contexts: Option<Vec<String>>,
+    /// Citation intents, one list per context.
+    #[serde(default = "Option::default", deserialize_with = "intents_per_context")]
+    pub intents: Option<Vec<Vec<String>>>,
+}
+
+/// A record of the `tldrs` dataset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TldrRecord {
+    #[serde(rename = "corpusid", alias = "corpusId", default = "Option::default")]
+    pub corpus_id: Option<CorpusId>,
+    #[serde(default = "Option::default")]
+    pub model: Option<String>,
+    #[serde(default = "Option::default")]
+    pub text: Option<String>,
+}
+
+/// Accept both `[["methodology"], []]` and a flat `["methodology"]`.
+fn intents_per_context<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<Vec<String>>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Intents {
+        One(String),
+        Many(Option<Vec<String>>),
+    }
+    let intents = Option::<Vec<Intents>>::deserialize(deserializer)?;
+    Ok(intents.map(|intents| {
+        intents
+            .into_iter()
+            .map(|intents| match intents {
+                Intents::One(intent) => vec![intent],
+                Intents::Many(intents) => intents.unwrap_or_default(),
+            })
+            .collect()
+    }))
+}
+
+/// Lazily decodes one `T` per non-empty line of a JSONL file, gzipped or not.
+pub struct JsonlReader<T> {
+    path: String,
+    reader: Box<dyn BufRead + Send>,
+    line: usize,
+    buffer: String,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> JsonlReader<T> {
+    /// Open a shard. Gzip is detected from the file content, not the extension.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|source| Error::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        Self::from_reader(file, &path.display().to_string())
+    }
+
+    /// Read records from any reader, e.g. an HTTP response body. `name` is used in errors.
+    pub fn from_reader(reader: impl Read + Send + 'static, name: &str) -> Result<Self> {
+        let mut reader = BufReader::new(reader);
+        let is_gzip = reader
+            .fill_buf()
+            .map_err(|source| Error::Io {
+                path: name.to_string(),
+                source,
+            })?
+            .starts_with(&[0x1f, 0x8b]);
+        let reader: Box<dyn BufRead + Send> = if is_gzip {
+            Box::new(BufReader::new(MultiGzDecoder::new(reader)))
+        } else {
+            Box::new(reader)
+        };
+        Ok(Self {
+            path: name.to_string(),
+            reader,
+            line: 0,
+            buffer: String::new(),
+            _record: PhantomData,
+        })
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for JsonlReader<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+            self.line += 1;
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) if self.buffer.trim().is_empty() => continue,
+                Ok(_) => {
+                    return Some(serde_json::from_str(&self.buffer).map_err(|source| {
+                        Error::Record {
+                            path: self.path.clone(),
+                            line: self.line,
+                            source,
+                        }
+                    }))
+                }
+                Err(source) => {
+                    return Some(Err(Error::Io {
+                        path: self.path.clone(),
+                        source,
+                    }))
+                }
+            }
+        }
+    }
+}
+
+/// Read a shard of the `papers` (or `abstracts`) dataset.
+pub fn read_papers(path: impl AsRef<Path>) -> Result<JsonlReader<Paper>> {
+    JsonlReader::open(path)
+}
+
+/// Read a shard of the `authors` dataset.
+pub fn read_authors(path: impl AsRef<Path>) -> Result<JsonlReader<Author>> {
+    JsonlReader::open(path)
+}
+
+/// Read a shard of the `citations` dataset.
+pub fn read_citations(path: impl AsRef<Path>) -> Result<JsonlReader<CitationRecord>> {
+    JsonlReader::open(path)
+}
+
+/// Read a shard of the `tldrs` dataset.
+pub fn read_tldrs(path: impl AsRef<Path>) -> Result<JsonlReader<TldrRecord>> {
+    JsonlReader::open(path)
+}
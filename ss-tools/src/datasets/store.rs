@@ -0,0 +1,205 @@
+//! # Incremental Updates
+//! Keep a local mirror current by applying the diffs returned by
+//! [`SemanticScholar::get_dataset_diffs`](crate::SemanticScholar::get_dataset_diffs)
+//! instead of downloading every release in full.
+//!
+//! Each diff lists `update_files`, whose records replace (or add) the record with the same key,
+//! and `delete_files`, whose records only carry the key of a record to remove.
+//! Diffs must be applied oldest first, and within a diff updates come before deletions.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use anyhow::Result;
+//! # use ss_tools::datasets::read_papers;
+//! # use ss_tools::datasets::store::{apply_diffs, DiffFiles, MemoryStore};
+//! # use ss_tools::structs::Paper;
+//! # fn main() -> Result<()> {
+//! // Full release, keyed by corpus id
+//! let mut store = MemoryStore::<Paper>::new();
+//! store.load(read_papers("papers/part-0.jsonl.gz")?)?;
+//!
+//! // Diff files downloaded from `DatasetDiff::update_files` / `DatasetDiff::delete_files`
+//! let diffs = vec![DiffFiles {
+//!     update_files: vec!["diffs/0/update-0.jsonl.gz".into()],
+//!     delete_files: vec!["diffs/0/delete-0.jsonl.gz".into()],
+//! }];
+//! let stats = apply_diffs(&mut store, &diffs)?;
+//! println!("{} upserted, {} deleted", stats.upserted, stats.deleted);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::datasets::{CitationRecord, JsonlReader, TldrRecord};
+use crate::error::Result;
+use crate::structs::{Author, CorpusId, Paper};
+use fxhash::FxHashMap;
+use serde::de::DeserializeOwned;
+use std::hash::Hash;
+use std::path::PathBuf;
+
+/// A dataset record with a primary key.
+pub trait Keyed {
+    type Key: Clone + Eq + Hash;
+
+    /// `None` for records without a key, which are skipped.
+    fn key(&self) -> Option<Self::Key>;
+}
+
+impl Keyed for Paper {
+    type Key = CorpusId;
+
+    fn key(&self) -> Option<Self::Key> {
+        self.corpus_id
+    }
+}
+
+impl Keyed for Author {
+    type Key = String;
+
+    fn key(&self) -> Option<Self::Key> {
+        self.author_id.clone()
+    }
+}
+
+impl Keyed for CitationRecord {
+    type Key = u64;
+
+    fn key(&self) -> Option<Self::Key> {
+        self.citation_id
+    }
+}
+
+impl Keyed for TldrRecord {
+    type Key = CorpusId;
+
+    fn key(&self) -> Option<Self::Key> {
+        self.corpus_id
+    }
+}
+
+/// Storage a diff can be applied to, e.g. a database table keyed by corpus id.
+pub trait DatasetStore<T: Keyed> {
+    /// Insert `record`, replacing any record with the same key.
+    fn upsert(&mut self, key: T::Key, record: T) -> Result<()>;
+    /// Remove the record with `key`, if any.
+    fn delete(&mut self, key: &T::Key) -> Result<()>;
+}
+
+/// An in-memory [`DatasetStore`].
+#[derive(Debug, Clone)]
+pub struct MemoryStore<T: Keyed> {
+    records: FxHashMap<T::Key, T>,
+}
+
+impl<T: Keyed> Default for MemoryStore<T> {
+    fn default() -> Self {
+        Self {
+            records: FxHashMap::default(),
+        }
+    }
+}
+
+impl<T: Keyed> MemoryStore<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every record of a full release shard.
+    pub fn load(&mut self, records: impl IntoIterator<Item = Result<T>>) -> Result<usize> {
+        let mut loaded = 0;
+        for record in records {
+            let record = record?;
+            if let Some(key) = record.key() {
+                self.records.insert(key, record);
+                loaded += 1;
+            }
+        }
+        Ok(loaded)
+    }
+
+    pub fn get(&self, key: &T::Key) -> Option<&T> {
+        self.records.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&T::Key, &T)> {
+        self.records.iter()
+    }
+}
+
+impl<T: Keyed> DatasetStore<T> for MemoryStore<T> {
+    fn upsert(&mut self, key: T::Key, record: T) -> Result<()> {
+        self.records.insert(key, record);
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &T::Key) -> Result<()> {
+        self.records.remove(key);
+        Ok(())
+    }
+}
+
+/// Local copies of the files of one [`DatasetDiff`](crate::structs::DatasetDiff).
+#[derive(Debug, Clone, Default)]
+pub struct DiffFiles {
+    pub update_files: Vec<PathBuf>,
+    pub delete_files: Vec<PathBuf>,
+}
+
+/// Number of records applied by [`apply_diff`] / [`apply_diffs`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStats {
+    pub upserted: usize,
+    pub deleted: usize,
+}
+
+/// Apply one diff: upsert every record of `diff.update_files`, then delete every key of
+/// `diff.delete_files`.
+pub fn apply_diff<T, S>(store: &mut S, diff: &DiffFiles) -> Result<DiffStats>
+where
+    T: Keyed + DeserializeOwned,
+    S: DatasetStore<T>,
+{
+    let mut stats = DiffStats::default();
+    for path in &diff.update_files {
+        for record in JsonlReader::<T>::open(path)? {
+            let record = record?;
+            if let Some(key) = record.key() {
+                store.upsert(key, record)?;
+                stats.upserted += 1;
+            }
+        }
+    }
+    for path in &diff.delete_files {
+        for record in JsonlReader::<T>::open(path)? {
+            if let Some(key) = record?.key() {
+                store.delete(&key)?;
+                stats.deleted += 1;
+            }
+        }
+    }
+    Ok(stats)
+}
+
+/// Apply a chain of diffs, oldest first.
+pub fn apply_diffs<T, S>(store: &mut S, diffs: &[DiffFiles]) -> Result<DiffStats>
+where
+    T: Keyed + DeserializeOwned,
+    S: DatasetStore<T>,
+{
+    let mut total = DiffStats::default();
+    for diff in diffs {
+        let stats = apply_diff(store, diff)?;
+        total.upserted += stats.upserted;
+        total.deleted += stats.deleted;
+    }
+    Ok(total)
+}
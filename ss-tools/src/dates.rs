@@ -0,0 +1,394 @@
+//! # Dates and Ranges
+//! Typed values for the `year` and `publicationDateOrYear` filters of the search endpoints,
+//! so a malformed filter is rejected when it is parsed instead of by the API.
+//!
+//! | Type | Format | Examples |
+//! |------|--------|----------|
+//! | [`PartialDate`] | `YYYY`, `YYYY-MM` or `YYYY-MM-DD` | `2019`, `2020-06`, `2020-06-15` |
+//! | [`YearRange`] | `YYYY`, `YYYY-YYYY`, `YYYY-` or `-YYYY` | `2019`, `2016-2020`, `2010-`, `-2015` |
+//! | [`DateRange`] | a date, or `start:end` with either side open | `2019-03-05`, `2016-03-05:2020-06-06`, `2020-06:` |
+//!
+//! # Example
+//!
+//! ```rust
+//! # use anyhow::Result;
+//! # use ss_tools::QueryParams;
+//! # use ss_tools::dates::{DateRange, YearRange};
+//! # fn main() -> Result<()> {
+//! let mut query_params = QueryParams::default();
+//! query_params
+//!     .year(YearRange::between(2016, 2020))
+//!     .publication_date_or_year("2020-06:".parse::<DateRange>()?);
+//!
+//! assert!("2020-13".parse::<DateRange>().is_err());
+//! assert!("2021-2019".parse::<YearRange>().is_err());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `chrono` feature, [`PartialDate`] converts from and to `chrono::NaiveDate`.
+
+use crate::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A year, a month or a day, e.g. `2020`, `2020-06` or `2020-06-15`.
+///
+/// Dates are ordered by year, then month, then day; a year or month sorts before the
+/// dates within it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PartialDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl PartialDate {
+    pub fn year(year: u16) -> Self {
+        PartialDate {
+            year,
+            month: None,
+            day: None,
+        }
+    }
+
+    /// `None` if `month` is not in `1..=12`.
+    pub fn month(year: u16, month: u8) -> Option<Self> {
+        (1..=12).contains(&month).then_some(PartialDate {
+            year,
+            month: Some(month),
+            day: None,
+        })
+    }
+
+    /// `None` if the day does not exist, e.g. `2023-02-29`.
+    pub fn day(year: u16, month: u8, day: u8) -> Option<Self> {
+        let date = PartialDate::month(year, month)?;
+        (1..=days_in_month(year, month))
+            .contains(&day)
+            .then_some(PartialDate {
+                day: Some(day),
+                ..date
+            })
+    }
+
+    pub fn get_year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn get_month(&self) -> Option<u8> {
+        self.month
+    }
+
+    pub fn get_day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// `true` for `YYYY-MM-DD` dates.
+    pub fn is_full(&self) -> bool {
+        self.day.is_some()
+    }
+
+    /// First day covered, as `(year, month, day)`.
+    fn first_day(&self) -> (u16, u8, u8) {
+        (self.year, self.month.unwrap_or(1), self.day.unwrap_or(1))
+    }
+
+    /// Last day covered, as `(year, month, day)`.
+    fn last_day(&self) -> (u16, u8, u8) {
+        let month = self.month.unwrap_or(12);
+        let day = self.day.unwrap_or_else(|| days_in_month(self.year, month));
+        (self.year, month, day)
+    }
+
+    /// `true` if `self` and `end` bound a non-empty range, e.g. `2020-06` to `2020`.
+    fn can_precede(&self, end: &PartialDate) -> bool {
+        self.first_day() <= end.last_day()
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidDate(s.to_string());
+        let parts = s.trim().split('-').collect::<Vec<_>>();
+        let number = |part: &str, len: usize| {
+            if part.len() == len && part.chars().all(|c| c.is_ascii_digit()) {
+                part.parse::<u16>().map_err(|_| invalid())
+            } else {
+                Err(invalid())
+            }
+        };
+        let date = match parts.as_slice() {
+            [year] => Some(PartialDate::year(number(year, 4)?)),
+            [year, month] => PartialDate::month(number(year, 4)?, number(month, 2)? as u8),
+            [year, month, day] => PartialDate::day(
+                number(year, 4)?,
+                number(month, 2)? as u8,
+                number(day, 2)? as u8,
+            ),
+            _ => None,
+        };
+        date.ok_or_else(invalid)
+    }
+}
+
+/// Value of the `year` filter: a single year or an inclusive range, open on at most one side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearRange {
+    start: Option<u16>,
+    end: Option<u16>,
+}
+
+impl YearRange {
+    /// Only `year`.
+    pub fn year(year: u16) -> Self {
+        YearRange {
+            start: Some(year),
+            end: Some(year),
+        }
+    }
+
+    /// `start` to `end`, both included. The bounds are swapped if `start > end`.
+    pub fn between(start: u16, end: u16) -> Self {
+        YearRange {
+            start: Some(start.min(end)),
+            end: Some(start.max(end)),
+        }
+    }
+
+    /// `start` and later.
+    pub fn since(start: u16) -> Self {
+        YearRange {
+            start: Some(start),
+            end: None,
+        }
+    }
+
+    /// `end` and earlier.
+    pub fn until(end: u16) -> Self {
+        YearRange {
+            start: None,
+            end: Some(end),
+        }
+    }
+
+    pub fn start(&self) -> Option<u16> {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<u16> {
+        self.end
+    }
+
+    pub fn contains(&self, year: u16) -> bool {
+        self.start.is_none_or(|start| start <= year) && self.end.is_none_or(|end| year <= end)
+    }
+}
+
+impl fmt::Display for YearRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if start == end => write!(f, "{}", start),
+            (Some(start), Some(end)) => write!(f, "{}-{}", start, end),
+            (Some(start), None) => write!(f, "{}-", start),
+            (None, Some(end)) => write!(f, "-{}", end),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl FromStr for YearRange {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidDate(s.to_string());
+        let year = |part: &str| -> Result<Option<u16>, Error> {
+            let part = part.trim();
+            if part.is_empty() {
+                Ok(None)
+            } else {
+                let date = PartialDate::from_str(part).map_err(|_| invalid())?;
+                Ok(Some(date.year))
+            }
+        };
+        let (start, end) = match s.split_once('-') {
+            Some((start, end)) => (year(start)?, year(end)?),
+            None => {
+                let year = year(s)?.ok_or_else(invalid)?;
+                (Some(year), Some(year))
+            }
+        };
+        match (start, end) {
+            (None, None) => Err(invalid()),
+            (Some(start), Some(end)) if start > end => Err(invalid()),
+            _ => Ok(YearRange { start, end }),
+        }
+    }
+}
+
+/// Value of the `publicationDateOrYear` filter: a single date or an inclusive range,
+/// open on at most one side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: Option<PartialDate>,
+    end: Option<PartialDate>,
+}
+
+impl DateRange {
+    /// Only `date` (a whole month or year for partial dates).
+    pub fn on(date: PartialDate) -> Self {
+        DateRange {
+            start: Some(date),
+            end: Some(date),
+        }
+    }
+
+    /// `start` to `end`, both included. The bounds are swapped if `start` begins after `end`
+    /// ends, so `2020-06` to `2020` is kept as is.
+    pub fn between(start: PartialDate, end: PartialDate) -> Self {
+        let (start, end) = if start.can_precede(&end) {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        DateRange {
+            start: Some(start),
+            end: Some(end),
+        }
+    }
+
+    /// `start` and later.
+    pub fn since(start: PartialDate) -> Self {
+        DateRange {
+            start: Some(start),
+            end: None,
+        }
+    }
+
+    /// `end` and earlier.
+    pub fn until(end: PartialDate) -> Self {
+        DateRange {
+            start: None,
+            end: Some(end),
+        }
+    }
+
+    pub fn start(&self) -> Option<PartialDate> {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<PartialDate> {
+        self.end
+    }
+}
+
+impl From<YearRange> for DateRange {
+    fn from(years: YearRange) -> Self {
+        DateRange {
+            start: years.start.map(PartialDate::year),
+            end: years.end.map(PartialDate::year),
+        }
+    }
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if start == end => write!(f, "{}", start),
+            (start, end) => {
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                write!(f, ":")?;
+                if let Some(end) = end {
+                    write!(f, "{}", end)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for DateRange {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidDate(s.to_string());
+        let date = |part: &str| -> Result<Option<PartialDate>, Error> {
+            let part = part.trim();
+            if part.is_empty() {
+                Ok(None)
+            } else {
+                part.parse().map(Some).map_err(|_| invalid())
+            }
+        };
+        let (start, end) = match s.split_once(':') {
+            Some((start, end)) => (date(start)?, date(end)?),
+            None => {
+                let date = date(s)?.ok_or_else(invalid)?;
+                (Some(date), Some(date))
+            }
+        };
+        match (start, end) {
+            (None, None) => Err(invalid()),
+            (Some(start), Some(end)) if !start.can_precede(&end) => Err(invalid()),
+            _ => Ok(DateRange { start, end }),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_support {
+    use super::PartialDate;
+    use crate::error::Error;
+    use chrono::{Datelike, NaiveDate};
+
+    impl TryFrom<NaiveDate> for PartialDate {
+        type Error = Error;
+
+        /// Fails with [`Error::InvalidDate`] if the year is outside `0..=65535`.
+        fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+            u16::try_from(date.year())
+                .ok()
+                .and_then(|year| PartialDate::day(year, date.month() as u8, date.day() as u8))
+                .ok_or_else(|| Error::InvalidDate(date.to_string()))
+        }
+    }
+
+    impl PartialDate {
+        /// The day of a `YYYY-MM-DD` date, `None` for a year or month.
+        pub fn to_naive_date(&self) -> Option<NaiveDate> {
+            NaiveDate::from_ymd_opt(
+                i32::from(self.year),
+                u32::from(self.month?),
+                u32::from(self.day?),
+            )
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::structs::Endpoint;
+use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A non-success HTTP response from the Semantic Scholar API.
+///
+/// `error` and `message` hold the corresponding keys of the JSON error body, e.g.
+/// `{"error": "Paper with id xxx not found"}` or `{"message": "Too Many Requests"}`.
+/// `retry_after` is the delay requested by the `Retry-After` header, if present.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub status: u16,
+    pub endpoint: Endpoint,
+    pub url: String,
+    pub error: Option<String>,
+    pub message: Option<String>,
+    pub retry_after: Option<Duration>,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HTTP {} from {:?} ({})",
+            self.status, self.endpoint, self.url
+        )?;
+        match (&self.error, &self.message) {
+            (Some(error), _) => write!(f, ": {}", error),
+            (None, Some(message)) => write!(f, ": {}", message),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// Errors returned by [`SemanticScholar`](crate::SemanticScholar).
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// 400 - e.g. an unknown field or a malformed query.
+    #[error("bad request: {0}")]
+    BadRequest(ApiError),
+    /// 401 / 403 - missing or invalid API key.
+    #[error("unauthorized: {0}")]
+    Unauthorized(ApiError),
+    /// 404 - unknown paper / author id or no title match.
+    #[error("not found: {0}")]
+    NotFound(ApiError),
+    /// 429 - too many requests.
+    #[error("rate limited: {0}")]
+    RateLimited(ApiError),
+    /// 5xx - error on the Semantic Scholar side.
+    #[error("server error: {0}")]
+    Server(ApiError),
+    /// Any other non-success status.
+    #[error("unexpected status: {0}")]
+    Status(ApiError),
+    #[error("request to {endpoint:?} timed out ({url})")]
+    Timeout { endpoint: Endpoint, url: String },
+    #[error("request to {endpoint:?} failed ({url}): {source}")]
+    Request {
+        endpoint: Endpoint,
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("failed to decode response from {endpoint:?} ({url}): {source}")]
+    Decode {
+        endpoint: Endpoint,
+        url: String,
+        body: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("empty response from {endpoint:?} ({url})")]
+    EmptyResponse { endpoint: Endpoint, url: String },
+    /// A batch endpoint returned a different number of entries than ids sent.
+    #[error("{endpoint:?} returned {actual} entries for {expected} ids ({url})")]
+    BatchLengthMismatch {
+        endpoint: Endpoint,
+        url: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("invalid client configuration: {0}")]
+    Config(String),
+    /// Fields the endpoint does not accept, caught before sending the request.
+    #[error("{endpoint:?} does not support the fields: {}", fields.join(", "))]
+    UnsupportedFields {
+        endpoint: Endpoint,
+        fields: Vec<String>,
+    },
+    /// A string that is not a recognized paper id, see [`PaperId`](crate::structs::PaperId).
+    #[error("unrecognized paper id: {0:?}")]
+    InvalidPaperId(String),
+    /// A name that is not a [`PaperField`](crate::structs::PaperField) or
+    /// [`AuthorField`](crate::structs::AuthorField).
+    #[error("unknown field: {0:?}")]
+    UnknownField(String),
+    /// A malformed date, year range or date range, see [`dates`](crate::dates).
+    #[error("invalid date or range: {0:?}")]
+    InvalidDate(String),
+    #[error("I/O error on {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A dataset record that does not match the expected struct.
+    #[error("failed to decode record at {path}:{line}: {source}")]
+    Record {
+        path: String,
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl Error {
+    pub(crate) fn from_status(
+        status: u16,
+        endpoint: Endpoint,
+        url: &str,
+        body: &str,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        let body = serde_json::from_str::<ErrorBody>(body).unwrap_or_default();
+        let api_error = ApiError {
+            status,
+            endpoint,
+            url: url.to_string(),
+            error: body.error,
+            message: body.message,
+            retry_after,
+        };
+        match status {
+            400 => Error::BadRequest(api_error),
+            401 | 403 => Error::Unauthorized(api_error),
+            404 => Error::NotFound(api_error),
+            429 => Error::RateLimited(api_error),
+            500..=599 => Error::Server(api_error),
+            _ => Error::Status(api_error),
+        }
+    }
+
+    pub(crate) fn from_reqwest(endpoint: Endpoint, url: &str, source: reqwest::Error) -> Self {
+        if source.is_timeout() {
+            Error::Timeout {
+                endpoint,
+                url: url.to_string(),
+            }
+        } else {
+            Error::Request {
+                endpoint,
+                url: url.to_string(),
+                source,
+            }
+        }
+    }
+
+    /// The API error body, if the request reached the API and got a non-success status.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Error::BadRequest(e)
+            | Error::Unauthorized(e)
+            | Error::NotFound(e)
+            | Error::RateLimited(e)
+            | Error::Server(e)
+            | Error::Status(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// HTTP status code of the response, if any.
+    pub fn status(&self) -> Option<u16> {
+        self.api_error().map(|e| e.status)
+    }
+
+    /// The endpoint that produced the error, if the error belongs to a request.
+    pub fn endpoint(&self) -> Option<&Endpoint> {
+        match self {
+            Error::Timeout { endpoint, .. }
+            | Error::Request { endpoint, .. }
+            | Error::Decode { endpoint, .. }
+            | Error::EmptyResponse { endpoint, .. }
+            | Error::BatchLengthMismatch { endpoint, .. }
+            | Error::UnsupportedFields { endpoint, .. } => Some(endpoint),
+            Error::Config(_)
+            | Error::InvalidPaperId(_)
+            | Error::UnknownField(_)
+            | Error::InvalidDate(_)
+            | Error::Io { .. }
+            | Error::Record { .. } => None,
+            _ => self.api_error().map(|e| &e.endpoint),
+        }
+    }
+
+    /// The request URL, if the error belongs to a request.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Error::Timeout { url, .. }
+            | Error::Request { url, .. }
+            | Error::Decode { url, .. }
+            | Error::EmptyResponse { url, .. }
+            | Error::BatchLengthMismatch { url, .. } => Some(url),
+            Error::Config(_)
+            | Error::UnsupportedFields { .. }
+            | Error::InvalidPaperId(_)
+            | Error::UnknownField(_)
+            | Error::InvalidDate(_)
+            | Error::Io { .. }
+            | Error::Record { .. } => None,
+            _ => self.api_error().map(|e| e.url.as_str()),
+        }
+    }
+}
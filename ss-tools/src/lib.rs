@@ -19,9 +19,7 @@
 //! let mut query_params = QueryParams::default();
 //! query_params.query_text(query_text);
 //!
-//! let max_retry_count = 5;
-//! let wait_time = 10;
-//! let paper = ss.query_a_paper_by_title(query_params, max_retry_count, wait_time).await.unwrap();
+//! let paper = ss.query_a_paper_by_title(query_params).await.unwrap();
 //
 //! assert_eq!(
 //!    paper.title.clone().unwrap().to_lowercase(),
@@ -37,33 +35,66 @@
 //! - Step 3 - [Build QueryParam](tutorials::step_3)
 //! - Step 4 - [Available Endpoints](tutorials::step_4)
 //!
+//! ## Errors
+//! Every method returns [`Result`], whose error type [`Error`] tells apart HTTP status classes
+//! (400, 401/403, 404, 429, 5xx), timeouts and JSON decode failures.
+//! [`Error::api_error`] exposes the status, the API's `error` / `message` body, the request URL and the endpoint.
+//! Fields an endpoint does not accept are rejected with [`Error::UnsupportedFields`] before any request is sent
+//! (see [`QueryParams::validate`]).
+//!
+//! ## Bulk Datasets
+//! Shards downloaded from the Datasets API can be read lazily into [`structs::Paper`], [`structs::Author`]
+//! and citation edges with the [`datasets`] module.
+//!
 //! ## Implemented Endpoints
 //! | Endpoint | Implementation | Reference |
 //! | --- |:---:|:---:|
-//! | [Suggest paper query completions](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_autocomplete) | - | |
-//! | [Get details for multiple papers at once](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/post_graph_get_papers)| - | |
+//! | [Suggest paper query completions](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_autocomplete) | ✅ | [`SemanticScholar::autocomplete_papers`] |
+//! | [Get details for multiple papers at once](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/post_graph_get_papers)| ✅ | [`SemanticScholar::bulk_query_by_ids`] |
 //! | [Paper relevance search](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_paper_relevance_search) | ✅ | [`SemanticScholar::query_papers_by_title`] |
-//! | [Paper bulk search](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_paper_bulk_search) | ✅  | [`SemanticScholar::bulk_query_by_ids`] |
+//! | [Paper bulk search](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_paper_bulk_search) | ✅  | [`SemanticScholar::bulk_search_papers`], [`SemanticScholar::bulk_search_papers_stream`] |
 //! | [Paper title search](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_paper_title_search) | ✅ |[`SemanticScholar::query_a_paper_by_title`] |
 //! | [Details about a paper](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper) | ✅ | [`SemanticScholar::query_paper_details`] |
 //! | [Details about a paper's authors](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_authors) | ✅ | [`SemanticScholar::query_paper_authors`] |
 //! | [Details about a paper's citations](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_citations) | ✅ | [`SemanticScholar::query_paper_citations`] |
 //! | [Details about a paper's references](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_references) | ✅ | [`SemanticScholar::query_paper_references`] |
-//! | [Get details for multiple authors at once](https://api.semanticscholar.org/api-docs/#tag/Author-Data/operation/post_graph_get_authors) | - | |
+//! | [Text snippet search](https://api.semanticscholar.org/api-docs/#tag/Snippet-Text/operation/get_snippet_search) | ✅ | [`SemanticScholar::search_snippets`] |
+//! | [Get details for multiple authors at once](https://api.semanticscholar.org/api-docs/#tag/Author-Data/operation/post_graph_get_authors) | ✅ | [`SemanticScholar::bulk_query_authors`] |
 //! | [Search for authors by name](https://api.semanticscholar.org/api-docs/#tag/Author-Data/operation/get_graph_get_author_search) | ✅ | [`SemanticScholar::search_authors`] |
 //! | [Details about an author](https://api.semanticscholar.org/api-docs/#tag/Author-Data/operation/get_graph_get_author) | ✅ | [`SemanticScholar::query_author_details`] |
 //! | [Details about an author's papers](https://api.semanticscholar.org/api-docs/#tag/Author-Data/operation/get_graph_get_author_papers) | ✅ | [`SemanticScholar::query_author_papers`] |
+//! | [Get recommended papers for a single positive example paper](https://api.semanticscholar.org/api-docs/recommendations#tag/Paper-Recommendations/operation/get_papers_for_paper) | ✅ | [`SemanticScholar::recommend_for_paper`] |
+//! | [Get recommended papers for lists of positive and negative example papers](https://api.semanticscholar.org/api-docs/recommendations#tag/Paper-Recommendations/operation/post_papers) | ✅ | [`SemanticScholar::recommend_from_seeds`] |
+//! | [List releases](https://api.semanticscholar.org/api-docs/datasets#tag/Release-Data/operation/get_releases) | ✅ | [`SemanticScholar::list_releases`] |
+//! | [Datasets in a release](https://api.semanticscholar.org/api-docs/datasets#tag/Release-Data/operation/get_release) | ✅ | [`SemanticScholar::get_release`] |
+//! | [Download links for a dataset](https://api.semanticscholar.org/api-docs/datasets#tag/Release-Data/operation/get_dataset) | ✅ | [`SemanticScholar::get_dataset`] |
+//! | [Incremental diffs between releases](https://api.semanticscholar.org/api-docs/datasets#tag/Incremental-Updates/operation/get_diff) | ✅ | [`SemanticScholar::get_dataset_diffs`] |
 
+pub mod datasets;
+pub mod dates;
+pub mod error;
+pub mod pagination;
+pub mod rate_limit;
+pub mod retry;
 pub mod structs;
 pub mod tutorials;
+pub mod wait;
 
+use crate::dates::{DateRange, YearRange};
+pub use crate::error::{ApiError, Error, Result};
+use crate::pagination::{paginate, paginate_token};
+use crate::rate_limit::RateLimiters;
+pub use crate::rate_limit::{RateLimit, RateLimiter};
+pub use crate::retry::RetryPolicy;
 use crate::structs::*;
-use anyhow::{Error, Result};
+pub use crate::wait::{WaitEvent, WaitReporter};
 use dotenvy::dotenv;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
 use fxhash::FxHashMap;
-use indicatif::ProgressBar;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC as NON_ALNUM};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC as NON_ALNUM};
 use reqwest::{self as request, header};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
 
 #[cfg(test)]
 mod tests;
@@ -72,6 +103,32 @@ fn encode(s: &str) -> String {
     utf8_percent_encode(s, NON_ALNUM).to_string()
 }
 
+// Unreserved characters (RFC 3986) stay as-is in path segments, e.g. release ids like `2024-01-02`
+const PATH_SEGMENT: &AsciiSet = &NON_ALNUM
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+fn encode_path_segment(s: &str) -> String {
+    utf8_percent_encode(s, PATH_SEGMENT).to_string()
+}
+
+// Paper ids keep their `DOI:` / `URL:` prefix and the slashes of DOIs and URLs
+const PAPER_ID: &AsciiSet = &PATH_SEGMENT.remove(b':').remove(b'/');
+
+fn encode_paper_id(paper_id: &str) -> String {
+    utf8_percent_encode(paper_id, PAPER_ID).to_string()
+}
+
+/// The canonical form of `paper_id`, or `paper_id` itself when it is not a recognized [`PaperId`].
+fn normalize_paper_id(paper_id: &str) -> String {
+    paper_id
+        .parse::<PaperId>()
+        .map(|id| id.to_string())
+        .unwrap_or_else(|_| paper_id.to_string())
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct QueryParams {
     pub paper_id: String,
@@ -80,19 +137,23 @@ pub struct QueryParams {
     pub author_fields: Option<Vec<AuthorField>>,
     pub publication_types: Option<Vec<PublicationTypes>>,
     pub open_access_pdf: Option<bool>,
-    pub min_citation_count: Option<u32>,
-    pub publication_date_or_year: Option<String>,
-    pub year: Option<String>,
+    pub min_citation_count: Option<u64>,
+    pub publication_date_or_year: Option<DateRange>,
+    pub year: Option<YearRange>,
     pub venue: Option<Vec<String>>,
     pub fields_of_study: Option<Vec<FieldsOfStudy>>,
     pub offset: Option<u64>,
     pub limit: Option<u64>,
     pub token: Option<String>,
     pub sort: Option<String>,
+    pub pool: Option<RecommendationPool>,
+    pub paper_ids: Option<Vec<String>>,
 }
 
 impl QueryParams {
-    pub fn paper_id(&mut self, paper_id: &str) -> &mut Self {
+    /// A [`PaperId`] or any string it can parse; paper endpoints send the normalized form.
+    /// Author endpoints take the author id through this setter as well.
+    pub fn paper_id(&mut self, paper_id: impl ToString) -> &mut Self {
         self.paper_id = paper_id.to_string();
         self
     }
@@ -116,16 +177,16 @@ impl QueryParams {
         self.open_access_pdf = Some(open_access_pdf);
         self
     }
-    pub fn min_citation_count(&mut self, min_citation_count: u32) -> &mut Self {
+    pub fn min_citation_count(&mut self, min_citation_count: u64) -> &mut Self {
         self.min_citation_count = Some(min_citation_count);
         self
     }
-    pub fn publication_date_or_year(&mut self, publication_date_or_year: &str) -> &mut Self {
-        self.publication_date_or_year = Some(publication_date_or_year.to_string());
+    pub fn publication_date_or_year(&mut self, publication_date_or_year: DateRange) -> &mut Self {
+        self.publication_date_or_year = Some(publication_date_or_year);
         self
     }
-    pub fn year(&mut self, year: &str) -> &mut Self {
-        self.year = Some(year.to_string());
+    pub fn year(&mut self, year: YearRange) -> &mut Self {
+        self.year = Some(year);
         self
     }
     pub fn venue(&mut self, venue: Vec<&str>) -> &mut Self {
@@ -153,6 +214,44 @@ impl QueryParams {
         self.sort = Some(sort.to_string());
         self
     }
+    pub fn pool(&mut self, pool: RecommendationPool) -> &mut Self {
+        self.pool = Some(pool);
+        self
+    }
+    /// Restrict the results to these papers (snippet search).
+    pub fn paper_ids(&mut self, paper_ids: Vec<&str>) -> &mut Self {
+        let paper_ids: Vec<String> = paper_ids.iter().map(|id| id.to_string()).collect();
+        self.paper_ids = Some(paper_ids);
+        self
+    }
+
+    /// Check that `endpoint` accepts the requested `fields` and `author_fields`.
+    /// Every endpoint method runs this before sending the request.
+    pub fn validate(&self, endpoint: &Endpoint) -> Result<()> {
+        let mut unsupported =
+            endpoint.unsupported_fields(self.fields.as_deref().unwrap_or_default());
+        unsupported.extend(
+            endpoint.unsupported_author_fields(self.author_fields.as_deref().unwrap_or_default()),
+        );
+        if unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::UnsupportedFields {
+                endpoint: endpoint.clone(),
+                fields: unsupported,
+            })
+        }
+    }
+
+    /// Make sure `PaperField::PaperId` is requested, the API omits it unless asked for.
+    fn require_paper_id(&mut self) -> &mut Self {
+        let mut fields = self.fields.clone().unwrap_or_default();
+        if !fields.contains(&PaperField::PaperId) {
+            fields.push(PaperField::PaperId);
+            self.fields = Some(fields);
+        }
+        self
+    }
 
     fn fields2string(&self, fields: Vec<PaperField>) -> String {
         fields
@@ -165,7 +264,7 @@ impl QueryParams {
     fn publication_types2string(&self, publication_types: Vec<PublicationTypes>) -> String {
         publication_types
             .iter()
-            .map(|publication_type| encode(&publication_type.to_string()))
+            .map(|publication_type| encode(publication_type.api_name()))
             .collect::<Vec<String>>()
             .join(",")
     }
@@ -173,7 +272,7 @@ impl QueryParams {
     fn fields_of_study2string(&self, fields_of_study: Vec<FieldsOfStudy>) -> String {
         fields_of_study
             .iter()
-            .map(|field| encode(&field.to_string()))
+            .map(|field| encode(field.api_name()))
             .collect::<Vec<String>>()
             .join(",")
     }
@@ -238,139 +337,546 @@ impl QueryParams {
             query_params.push(format!("limit={}", limit));
         }
         if let Some(token) = &self.token {
-            query_params.push(format!("token={}", token));
+            query_params.push(format!("token={}", encode(token)));
         }
         if let Some(sort) = &self.sort {
             query_params.push(format!("sort={}", sort));
         }
+        if let Some(pool) = &self.pool {
+            query_params.push(format!("from={}", pool));
+        }
+        if let Some(paper_ids) = &self.paper_ids {
+            let paper_ids = paper_ids
+                .iter()
+                .map(|id| encode(id))
+                .collect::<Vec<String>>()
+                .join(",");
+            query_params.push(format!("paperIds={}", paper_ids));
+        }
 
         if query_params.is_empty() {
-            return "".to_string();
+            "".to_string()
         } else {
             let query_params = query_params.join("&");
-            return format!("?{}", query_params);
+            format!("?{}", query_params)
         }
     }
 }
 
-#[derive(Clone, Debug, Default)]
+const DEFAULT_BASE_URL: &str = "https://api.semanticscholar.org/graph/v1";
+const DEFAULT_RECOMMENDATIONS_BASE_URL: &str = "https://api.semanticscholar.org/recommendations/v1";
+const DEFAULT_DATASETS_BASE_URL: &str = "https://api.semanticscholar.org/datasets/v1";
+const DEFAULT_USER_AGENT: &str = concat!("ss-tools/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Most ids `/paper/batch` accepts in one request.
+pub const MAX_PAPER_BATCH_SIZE: usize = 500;
+/// Most ids `/author/batch` accepts in one request.
+pub const MAX_AUTHOR_BATCH_SIZE: usize = 1000;
+
+/// Builder for [`SemanticScholar`].
+///
+/// Every setting falls back to the public Semantic Scholar defaults, so only the values
+/// that differ need to be set. If no API key is given, `SEMANTIC_SCHOLAR_API_KEY` is read
+/// from the environment (or a `.env` file) when [`SemanticScholarBuilder::build`] is called.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyhow::Result;
+/// # use ss_tools::SemanticScholar;
+/// # fn main() -> Result<()> {
+/// let ss = SemanticScholar::builder()
+///     .base_url("http://localhost:8080/graph/v1")
+///     .api_key("xxxxxxxx")
+///     .timeout(std::time::Duration::from_secs(10))
+///     .user_agent("my-crawler/1.0")
+///     .build()?;
+/// assert_eq!(ss.base_url(), "http://localhost:8080/graph/v1");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SemanticScholarBuilder {
+    api_key: Option<String>,
+    base_url: String,
+    recommendations_base_url: String,
+    datasets_base_url: String,
+    timeout: Duration,
+    user_agent: String,
+    proxy: Option<String>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    client: Option<request::Client>,
+    retry_policy: RetryPolicy,
+    wait_reporter: WaitReporter,
+    batch_concurrency: usize,
+    // `None` means "1 request per second if an API key is set, unlimited otherwise"
+    rate_limit: Option<Option<RateLimit>>,
+    endpoint_rate_limits: FxHashMap<Endpoint, RateLimit>,
+}
+
+impl Default for SemanticScholarBuilder {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            recommendations_base_url: DEFAULT_RECOMMENDATIONS_BASE_URL.to_string(),
+            datasets_base_url: DEFAULT_DATASETS_BASE_URL.to_string(),
+            timeout: DEFAULT_TIMEOUT,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            client: None,
+            retry_policy: RetryPolicy::default(),
+            wait_reporter: WaitReporter::default(),
+            batch_concurrency: 1,
+            rate_limit: None,
+            endpoint_rate_limits: FxHashMap::default(),
+        }
+    }
+}
+
+impl SemanticScholarBuilder {
+    /// Base URL of the Graph API, e.g. `https://api.semanticscholar.org/graph/v1`.
+    pub fn base_url(&mut self, base_url: &str) -> &mut Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+    /// Base URL of the Recommendations API, e.g. `https://api.semanticscholar.org/recommendations/v1`.
+    pub fn recommendations_base_url(&mut self, base_url: &str) -> &mut Self {
+        self.recommendations_base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+    /// Base URL of the Datasets API, e.g. `https://api.semanticscholar.org/datasets/v1`.
+    pub fn datasets_base_url(&mut self, base_url: &str) -> &mut Self {
+        self.datasets_base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+    pub fn api_key(&mut self, api_key: &str) -> &mut Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+    pub fn user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+    /// Route every request through the given proxy URL, e.g. `http://127.0.0.1:3128`.
+    pub fn proxy(&mut self, proxy: &str) -> &mut Self {
+        self.proxy = Some(proxy.to_string());
+        self
+    }
+
+    /// Maximum number of idle connections kept alive per host.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) -> &mut Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+    /// How long an idle pooled connection is kept before it is closed.
+    pub fn pool_idle_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+    /// Use an existing `reqwest::Client` instead of building one.  
+    /// The client is used as-is: `timeout`, `user_agent`, `proxy` and the pool settings are ignored.
+    /// The API key is still attached to every request.
+    pub fn client(&mut self, client: request::Client) -> &mut Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// How failed requests are retried, see [`RetryPolicy`].
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// How waits between retries are reported, see [`WaitReporter`].
+    pub fn wait_reporter(&mut self, wait_reporter: WaitReporter) -> &mut Self {
+        self.wait_reporter = wait_reporter;
+        self
+    }
+
+    /// Number of chunks of a batch request ([`SemanticScholar::bulk_query_by_ids`],
+    /// [`SemanticScholar::bulk_query_authors`]) sent at the same time. Defaults to `1`.
+    pub fn batch_concurrency(&mut self, batch_concurrency: usize) -> &mut Self {
+        self.batch_concurrency = batch_concurrency.max(1);
+        self
+    }
+
+    /// Client-side budget shared by every endpoint without its own budget.  
+    /// Defaults to 1 request per second when an API key is set and to no limit otherwise;
+    /// `None` disables the default budget.
+    pub fn rate_limit(&mut self, rate_limit: Option<RateLimit>) -> &mut Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+    /// Separate budget for one endpoint, e.g. `/paper/batch` or `/paper/search`.
+    pub fn endpoint_rate_limit(&mut self, endpoint: Endpoint, rate_limit: RateLimit) -> &mut Self {
+        self.endpoint_rate_limits.insert(endpoint, rate_limit);
+        self
+    }
+
+    fn http_client(&self) -> Result<request::Client> {
+        if let Some(client) = &self.client {
+            return Ok(client.clone());
+        }
+        let mut builder = request::Client::builder()
+            .user_agent(self.user_agent.clone())
+            .timeout(self.timeout);
+        if let Some(proxy) = &self.proxy {
+            let proxy = request::Proxy::all(proxy)
+                .map_err(|e| Error::Config(format!("invalid proxy {}: {}", proxy, e)))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        builder
+            .build()
+            .map_err(|e| Error::Config(format!("failed to build HTTP client: {}", e)))
+    }
+
+    pub fn build(&self) -> Result<SemanticScholar> {
+        let client = self.http_client()?;
+        let api_key = match &self.api_key {
+            Some(api_key) => api_key.clone(),
+            None => {
+                dotenv().ok();
+                let vars = FxHashMap::from_iter(std::env::vars());
+                vars.get("SEMANTIC_SCHOLAR_API_KEY")
+                    .cloned()
+                    .unwrap_or_default()
+            }
+        };
+        let rate_limit = match self.rate_limit {
+            Some(rate_limit) => rate_limit,
+            None if api_key.is_empty() => None,
+            None => Some(RateLimit::per_second(1)),
+        };
+        let rate_limiters = RateLimiters::new(rate_limit, &self.endpoint_rate_limits);
+        Ok(SemanticScholar {
+            api_key,
+            base_url: self.base_url.clone(),
+            recommendations_base_url: self.recommendations_base_url.clone(),
+            datasets_base_url: self.datasets_base_url.clone(),
+            client,
+            retry_policy: self.retry_policy.clone(),
+            wait_reporter: self.wait_reporter.clone(),
+            batch_concurrency: self.batch_concurrency,
+            rate_limiters,
+        })
+    }
+}
+
+/// Client for the Semantic Scholar APIs.
+///
+/// The client owns a single pooled `reqwest::Client` and its rate limiters, so cloning it is
+/// cheap and every clone shares the same connections and request budget.
+#[derive(Clone, Debug)]
 pub struct SemanticScholar {
     pub api_key: String,
+    base_url: String,
+    recommendations_base_url: String,
+    datasets_base_url: String,
+    client: request::Client,
+    retry_policy: RetryPolicy,
+    wait_reporter: WaitReporter,
+    batch_concurrency: usize,
+    rate_limiters: RateLimiters,
+}
+
+impl Default for SemanticScholar {
+    fn default() -> Self {
+        SemanticScholarBuilder::default()
+            .api_key("")
+            .build()
+            .expect("default builder settings are always valid")
+    }
 }
 
 impl SemanticScholar {
+    /// Create a client for the public Semantic Scholar API.  
+    /// The API key is read from `SEMANTIC_SCHOLAR_API_KEY`. Use [`SemanticScholar::builder`] for other settings.
     pub fn new() -> Self {
-        dotenv().ok();
-        let vars = FxHashMap::from_iter(std::env::vars().into_iter().map(|(k, v)| (k, v)));
-        let api_key = vars
-            .get("SEMANTIC_SCHOLAR_API_KEY")
-            .unwrap_or(&"".to_string())
-            .to_string();
-        Self { api_key: api_key }
+        SemanticScholarBuilder::default()
+            .build()
+            .expect("default builder settings are always valid")
     }
 
-    fn get_url(&self, endpoint: Endpoint, query_params: &mut QueryParams) -> String {
-        let paper_id = query_params.paper_id.clone();
+    pub fn builder() -> SemanticScholarBuilder {
+        SemanticScholarBuilder::default()
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn recommendations_base_url(&self) -> &str {
+        &self.recommendations_base_url
+    }
+
+    pub fn datasets_base_url(&self) -> &str {
+        &self.datasets_base_url
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    /// The rate limiter applied to `endpoint`, if any.
+    pub fn rate_limiter(&self, endpoint: &Endpoint) -> Option<&RateLimiter> {
+        self.rate_limiters.get(endpoint)
+    }
+
+    /// The shared HTTP client used for every request.
+    pub fn http_client(&self) -> &request::Client {
+        &self.client
+    }
+
+    fn get_url(&self, endpoint: Endpoint, query_params: &mut QueryParams) -> Result<String> {
+        query_params.validate(&endpoint)?;
+        let paper_id = encode_paper_id(&normalize_paper_id(&query_params.paper_id));
+        let author_id = query_params.paper_id.clone();
         let query_params = query_params.build();
-        match endpoint {
+        let base_url = &self.base_url;
+        let url = match endpoint {
             Endpoint::GetMultiplePpaerDetails => {
-                return format!(
-                    "https://api.semanticscholar.org/graph/v1/paper/batch{}",
-                    query_params
-                );
+                format!("{}/paper/batch{}", base_url, query_params)
             }
             Endpoint::GetAPaperByTitle => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/paper/search/match{}",
-                    query_params
-                );
-                return url;
+                format!("{}/paper/search/match{}", base_url, query_params)
             }
             Endpoint::GetPapersByTitle => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/paper/search{}",
-                    query_params
-                );
-                return url;
+                format!("{}/paper/search{}", base_url, query_params)
             }
             Endpoint::GetPaperDetails => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/paper/{}{}",
-                    paper_id, query_params
-                );
-                return url;
+                format!("{}/paper/{}{}", base_url, paper_id, query_params)
             }
             Endpoint::GetAuthorDetails => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/author/{}{}",
-                    paper_id, query_params
-                );
-                return url;
+                format!("{}/author/{}{}", base_url, author_id, query_params)
+            }
+            Endpoint::GetMultipleAuthorDetails => {
+                format!("{}/author/batch{}", base_url, query_params)
+            }
+            Endpoint::GetRecommendationsForPaper => format!(
+                "{}/papers/forpaper/{}{}",
+                self.recommendations_base_url, paper_id, query_params
+            ),
+            Endpoint::GetRecommendationsFromSeeds => {
+                format!("{}/papers{}", self.recommendations_base_url, query_params)
+            }
+            // Datasets API paths are built by the dataset methods themselves
+            Endpoint::ListReleases
+            | Endpoint::GetRelease
+            | Endpoint::GetDataset
+            | Endpoint::GetDatasetDiffs => {
+                return Err(Error::Config(format!(
+                    "{:?} is not a Graph or Recommendations API endpoint",
+                    endpoint
+                )))
             }
             Endpoint::GetReferencesOfAPaper => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/paper/{}/references{}",
-                    paper_id, query_params
-                );
-                return url;
+                format!("{}/paper/{}/references{}", base_url, paper_id, query_params)
             }
             Endpoint::GetCitationsOfAPaper => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/paper/{}/citations{}",
-                    paper_id, query_params
-                );
-                return url;
+                format!("{}/paper/{}/citations{}", base_url, paper_id, query_params)
             }
             Endpoint::SearchAuthors => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/author/search{}",
-                    query_params
-                );
-                return url;
+                format!("{}/author/search{}", base_url, query_params)
             }
             Endpoint::GetAuthorPapers => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/author/{}/papers{}",
-                    paper_id, query_params
-                );
-                return url;
+                format!("{}/author/{}/papers{}", base_url, author_id, query_params)
             }
             Endpoint::GetPaperAuthors => {
-                let url = format!(
-                    "https://api.semanticscholar.org/graph/v1/paper/{}/authors{}",
-                    paper_id, query_params
-                );
-                return url;
+                format!("{}/paper/{}/authors{}", base_url, paper_id, query_params)
+            }
+            Endpoint::SearchPapersInBulk => {
+                format!("{}/paper/search/bulk{}", base_url, query_params)
             }
+            Endpoint::AutocompletePapers => {
+                format!("{}/paper/autocomplete{}", base_url, query_params)
+            }
+            Endpoint::SearchSnippets => {
+                format!("{}/snippet/search{}", base_url, query_params)
+            }
+        };
+        Ok(url)
+    }
+
+    fn request(&self, method: request::Method, url: &str) -> request::RequestBuilder {
+        let mut request = self
+            .client
+            .request(method, url)
+            .header(header::CONTENT_TYPE, "application/json");
+        if !self.api_key.is_empty() {
+            request = request.header("x-api-key", &self.api_key);
         }
+        request
     }
 
-    async fn sleep(&self, seconds: u64, message: &str) {
-        let pb = ProgressBar::new(seconds);
-        pb.set_style(
-            indicatif::ProgressStyle::default_bar()
-                .template(
-                    "{spinner:.green} [{elapsed_precise}] [{bar:40.green/cyan}] {pos}s/{len}s {msg}",
-                )
-                .unwrap()
-                .progress_chars("█▓▒░"),
-        );
-        if message.is_empty() {
-            pb.set_message("Waiting for the next request...");
-        } else {
-            pb.set_message(message.to_string());
+    async fn fetch<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        method: request::Method,
+        url: &str,
+        body: Option<&str>,
+    ) -> Result<T> {
+        self.rate_limiters.acquire(&endpoint).await;
+        tracing::debug!(endpoint = ?endpoint, %method, %url, "sending request");
+        let mut request = self.request(method, url);
+        if let Some(body) = body {
+            request = request.body(body.to_string());
         }
-        for _ in 0..seconds {
-            pb.inc(1);
-            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::from_reqwest(endpoint.clone(), url, e))?;
+        let status = response.status();
+        tracing::debug!(endpoint = ?endpoint, %url, status = status.as_u16(), "received response");
+        let retry_after = response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry::parse_retry_after);
+        let body = response
+            .text()
+            .await
+            .map_err(|e| Error::from_reqwest(endpoint.clone(), url, e))?;
+        if !status.is_success() {
+            return Err(Error::from_status(
+                status.as_u16(),
+                endpoint,
+                url,
+                &body,
+                retry_after,
+            ));
+        }
+        serde_json::from_str::<T>(&body).map_err(|source| Error::Decode {
+            endpoint,
+            url: url.to_string(),
+            body,
+            source,
+        })
+    }
+
+    async fn execute<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        method: request::Method,
+        url: &str,
+        body: Option<&str>,
+    ) -> Result<T> {
+        let mut attempt = 1;
+        loop {
+            match self
+                .fetch(endpoint.clone(), method.clone(), url, body)
+                .await
+            {
+                Ok(response) => return Ok(response),
+                Err(e) if self.retry_policy.should_retry(attempt, &e) => {
+                    let event = WaitEvent {
+                        endpoint: endpoint.clone(),
+                        url: url.to_string(),
+                        attempt,
+                        delay: self.retry_policy.delay(attempt, &e),
+                        cause: e.to_string(),
+                    };
+                    self.wait_reporter.wait(event).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
         }
-        pb.finish_and_clear();
+    }
+
+    /// POST `ids` to a batch endpoint in chunks of `chunk_size`, [`SemanticScholarBuilder::batch_concurrency`]
+    /// chunks at a time. The result is aligned with `ids`, `None` marking ids the API does not know;
+    /// a response with a different number of entries than its chunk is an [`Error::BatchLengthMismatch`].
+    async fn execute_batch<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        url: &str,
+        ids: &[&str],
+        chunk_size: usize,
+    ) -> Result<Vec<Option<T>>> {
+        let chunks = stream::iter(ids.chunks(chunk_size))
+            .map(|chunk| {
+                let endpoint = endpoint.clone();
+                async move {
+                    let body = serde_json::json!({ "ids": chunk }).to_string();
+                    let response: Vec<Option<T>> = self
+                        .execute(endpoint.clone(), request::Method::POST, url, Some(&body))
+                        .await?;
+                    if response.len() != chunk.len() {
+                        return Err(Error::BatchLengthMismatch {
+                            endpoint,
+                            url: url.to_string(),
+                            expected: chunk.len(),
+                            actual: response.len(),
+                        });
+                    }
+                    Ok(response)
+                }
+            })
+            .buffered(self.batch_concurrency)
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(chunks.into_iter().flatten().collect())
     }
 
     /// # Description
-    /// Bulk retrieval of basic paper data without search relevance.  
+    /// Suggest paper titles completing a partial query, for interactive type-ahead.
+    /// The API only looks at the first 100 characters of `query`.
+    /// See for more details: [Suggest paper query completions](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_autocomplete)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use ss_tools::SemanticScholar;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::new();
+    /// let matches = ss.autocomplete_papers("attention is all").await?;
+    /// assert!(!matches.is_empty());
+    /// println!("{} ({:?})", matches[0].title, matches[0].authors_year);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn autocomplete_papers(&mut self, query: &str) -> Result<Vec<AutocompleteMatch>> {
+        let mut query_params = QueryParams::default();
+        query_params.query_text(query);
+        let url = self.get_url(Endpoint::AutocompletePapers, &mut query_params)?;
+        let response: AutocompleteResponse = self
+            .execute(
+                Endpoint::AutocompletePapers,
+                request::Method::GET,
+                &url,
+                None,
+            )
+            .await?;
+        Ok(response.matches)
+    }
+
+    /// # Description
+    /// Get details for multiple papers at once.  
+    /// Ids are sent in chunks of [`MAX_PAPER_BATCH_SIZE`]; the result is aligned with `paper_ids`,
+    /// with `None` for ids the API does not know.  
     /// Available fields for `fields: Vec<PaperField>`, see: [`PaperField`].  
-    /// See for more details: [Paper bulk search](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_paper_bulk_search)  
+    /// See for more details: [Get details for multiple papers at once](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/post_graph_get_papers)  
     ///
     /// # Example
     ///
@@ -389,76 +895,35 @@ impl SemanticScholar {
     ///     PaperField::Title,
     ///     PaperField::CitationCount,
     /// ];
-    /// let max_retry_count = 5;
-    /// let wait_time = 10;
     /// let mut ss = SemanticScholar::new();
-    /// let papers = ss.bulk_query_by_ids(paper_ids, fields, max_retry_count, wait_time).await.unwrap();
+    /// let papers = ss.bulk_query_by_ids(paper_ids, fields).await.unwrap();
     ///
     /// assert_eq!(papers.len(), 3);
-    /// let paper = &papers[0].clone();
+    /// let paper = papers[0].clone().unwrap();
     /// assert_eq!(paper.title.clone().unwrap(), "S2ORC: The Semantic Scholar Open Research Corpus");
     /// # Ok(())
     /// # }
     /// ```
     pub async fn bulk_query_by_ids(
         &mut self,
-        paper_ids: Vec<&str>,
+        paper_ids: Vec<impl ToString>,
         fields: Vec<PaperField>,
-        max_retry_count: u64,
-        wait_time: u64,
-    ) -> Result<Vec<Paper>> {
-        let mut max_retry_count = max_retry_count.clone();
-
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
-
+    ) -> Result<Vec<Option<Paper>>> {
+        let paper_ids: Vec<String> = paper_ids
+            .iter()
+            .map(|id| normalize_paper_id(&id.to_string()))
+            .collect();
+        let paper_ids: Vec<&str> = paper_ids.iter().map(String::as_str).collect();
         let mut query_params = QueryParams::default();
-        query_params.fields(fields.clone());
-        let url = self.get_url(Endpoint::GetMultiplePpaerDetails, &mut query_params);
-        let body = format!(
-            "{{\"ids\":[{}]}}",
-            paper_ids
-                .iter()
-                .map(|v| format!("\"{}\"", v))
-                .collect::<Vec<String>>()
-                .join(",")
-        );
-
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg("Failed to get papers"));
-            }
-            let body = client
-                .post(url.clone())
-                .body(body.clone())
-                .send()
-                .await?
-                .text()
-                .await?;
-
-            match serde_json::from_str::<Vec<Paper>>(&body) {
-                Ok(response) => {
-                    return Ok(response);
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(
-                        wait_time,
-                        format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                    ).await;
-                    continue;
-                }
-            }
-        }
+        query_params.fields(fields);
+        let url = self.get_url(Endpoint::GetMultiplePpaerDetails, &mut query_params)?;
+        self.execute_batch(
+            Endpoint::GetMultiplePpaerDetails,
+            &url,
+            &paper_ids,
+            MAX_PAPER_BATCH_SIZE,
+        )
+        .await
     }
 
     /// # Description
@@ -475,10 +940,8 @@ impl SemanticScholar {
     /// let mut ss = SemanticScholar::new();
     /// let mut query_params = QueryParams::default();
     /// query_params.query_text("attention is all you need");
-    /// let max_retry_count = 5;
-    /// let wait_time = 10;
     ///
-    /// let papers = ss.query_papers_by_title(query_params, max_retry_count, wait_time).await.unwrap();
+    /// let papers = ss.query_papers_by_title(query_params).await.unwrap();
     ///
     /// assert!(papers.len() > 1);
     /// let paper = papers.first().unwrap();
@@ -490,69 +953,134 @@ impl SemanticScholar {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn query_papers_by_title(
-        &mut self,
-        query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
-    ) -> Result<Vec<Paper>> {
+    pub async fn query_papers_by_title(&mut self, query_params: QueryParams) -> Result<Vec<Paper>> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
-
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
+        let url = self.get_url(Endpoint::GetPapersByTitle, &mut query_params)?;
+        let response: PaperIds = self
+            .execute(Endpoint::GetPapersByTitle, request::Method::GET, &url, None)
+            .await?;
+        if response.data.is_empty() || response.total == 0 {
+            return Err(Error::EmptyResponse {
+                endpoint: Endpoint::GetPapersByTitle,
+                url,
+            });
         }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
-
-        let url = self.get_url(Endpoint::GetPapersByTitle, &mut query_params);
-
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get paper id for: {}",
-                    query_params.query_text.unwrap().clone()
-                )));
-            }
+        Ok(response.data)
+    }
 
-            let body = client.get(url.clone()).send().await?.text().await?;
+    /// # Description
+    /// Bulk retrieval of basic paper data without search relevance.  
+    /// `query_params.query_text` supports the boolean query syntax (`+`, `|`, `-`, `"..."`, `*`, `~`), and
+    /// `query_params.sort` orders the results, e.g. `citationCount:desc` or `publicationDate:asc`.  
+    /// Returns one batch of up to 1,000 papers; pass the returned `token` back with [`QueryParams::token`] to get the next one,
+    /// or use [`SemanticScholar::bulk_search_papers_stream`].  
+    /// See for more details: [Paper bulk search](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_paper_bulk_search)  
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use ss_tools::{SemanticScholar, QueryParams};
+    /// # use ss_tools::structs::PaperField;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::new();
+    /// let mut query_params = QueryParams::default();
+    /// query_params.query_text("\"generative adversarial\" + (image | video) -medical");
+    /// query_params.fields(vec![PaperField::Title, PaperField::CitationCount]);
+    /// query_params.sort("citationCount:desc");
+    /// let response = ss.bulk_search_papers(query_params).await?;
+    /// assert!(response.total > 0);
+    /// assert!(!response.data.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bulk_search_papers(&mut self, query_params: QueryParams) -> Result<PaperIds> {
+        let mut query_params = query_params.clone();
+        let url = self.get_url(Endpoint::SearchPapersInBulk, &mut query_params)?;
+        self.execute(
+            Endpoint::SearchPapersInBulk,
+            request::Method::GET,
+            &url,
+            None,
+        )
+        .await
+    }
 
-            // "Title match not found" is a deterministic response — retrying won't help
-            if body.contains("Title match not found") {
-                return Err(Error::msg(format!(
-                    "Title match not found for: {}",
-                    query_params.query_text.as_deref().unwrap_or("unknown")
-                )));
-            }
+    /// # Description
+    /// Stream every paper matching a bulk search, following the continuation `token` batch by batch.
+    /// Query syntax and sorting work as in [`SemanticScholar::bulk_search_papers`]; a `token` set in
+    /// `query_params` resumes an interrupted search. Stop early with [`futures::StreamExt::take`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use futures::{StreamExt, TryStreamExt};
+    /// # use ss_tools::{SemanticScholar, QueryParams};
+    /// # use ss_tools::dates::YearRange;
+    /// # use ss_tools::structs::PaperField;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ss = SemanticScholar::new();
+    /// let mut query_params = QueryParams::default();
+    /// query_params.query_text("transformer");
+    /// query_params.fields(vec![PaperField::Title, PaperField::Year]);
+    /// query_params.year(YearRange::since(2020));
+    /// let papers = ss
+    ///     .bulk_search_papers_stream(query_params)
+    ///     .take(1500)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// assert_eq!(papers.len(), 1500);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bulk_search_papers_stream(
+        &self,
+        query_params: QueryParams,
+    ) -> impl Stream<Item = Result<Paper>> {
+        paginate_token(self.clone(), Endpoint::SearchPapersInBulk, query_params)
+    }
 
-            match serde_json::from_str::<PaperIds>(&body) {
-                Ok(response) => {
-                    if response.data.is_empty() || response.total == 0 {
-                        max_retry_count -= 1;
-                        self.sleep(
-                            wait_time,
-                            format!("Error: Response is empty. Body: {}", &body).as_str(),
-                        ).await;
-                        continue;
-                    }
-                    return Ok(response.data);
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(
-                        wait_time,
-                        format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                    ).await;
-                    continue;
-                }
-            }
-        }
+    /// # Description
+    /// Search text passages of open-access papers matching `query_params.query_text`.
+    /// Results can be filtered like a paper search (`year`, `publication_date_or_year`, `venue`,
+    /// `fields_of_study`, `min_citation_count`) and restricted to given papers with [`QueryParams::paper_ids`].
+    /// `query_params.limit` is at most 1000 (default 10).
+    /// See for more details: [Text snippet search](https://api.semanticscholar.org/api-docs/#tag/Snippet-Text/operation/get_snippet_search)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use ss_tools::{SemanticScholar, QueryParams};
+    /// # use ss_tools::dates::YearRange;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::new();
+    /// let mut query_params = QueryParams::default();
+    /// query_params.query_text("scaled dot-product attention");
+    /// query_params.year(YearRange::since(2017));
+    /// query_params.limit(5);
+    /// let response = ss.search_snippets(query_params).await?;
+    /// for result in &response.data {
+    ///     println!(
+    ///         "{:?} [{:?}] {}",
+    ///         result.paper.title, result.snippet.section, result.snippet.text
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_snippets(
+        &mut self,
+        query_params: QueryParams,
+    ) -> Result<SnippetSearchResponse> {
+        let mut query_params = query_params.clone();
+        let url = self.get_url(Endpoint::SearchSnippets, &mut query_params)?;
+        self.execute(Endpoint::SearchSnippets, request::Method::GET, &url, None)
+            .await
     }
 
     /// # Description
@@ -569,12 +1097,7 @@ impl SemanticScholar {
     /// let mut ss = SemanticScholar::new();
     /// let mut query_params = QueryParams::default();
     /// query_params.query_text("attention is all you need");
-    /// let max_retry_count = 5;
-    /// let wait_time = 10;
-    /// let paper = ss
-    ///     .query_a_paper_by_title(query_params, max_retry_count, wait_time)
-    ///     .await
-    ///     .unwrap();
+    /// let paper = ss.query_a_paper_by_title(query_params).await.unwrap();
     /// assert_eq!(paper.paper_id.clone().unwrap(), "204e3073870fae3d05bcbc2f6a8e263d9b72e776");
     /// assert_eq!(
     ///     paper.title.clone().unwrap().to_lowercase(),
@@ -583,73 +1106,26 @@ impl SemanticScholar {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn query_a_paper_by_title(
-        &mut self,
-        query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
-    ) -> Result<Paper> {
+    pub async fn query_a_paper_by_title(&mut self, query_params: QueryParams) -> Result<Paper> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
-
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse()?);
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()?;
-
-        let url = self.get_url(Endpoint::GetAPaperByTitle, &mut query_params);
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get paper id for: {}",
-                    query_params.query_text.unwrap()
-                )));
-            }
-
-            let body = client.get(url.clone()).send().await?.text().await?;
-
-            // "Title match not found" is a deterministic response — retrying won't help
-            if body.contains("Title match not found") {
-                return Err(Error::msg(format!(
-                    "Title match not found for: {}",
-                    query_params.query_text.as_deref().unwrap_or("unknown")
-                )));
-            }
-
-            match serde_json::from_str::<PaperIds>(&body) {
-                Ok(response) => {
-                    if response.data.len() < 1 {
-                        max_retry_count -= 1;
-                        self.sleep(
-                            wait_time,
-                            format!("Error: Response is empty. Body: {}", &body).as_str(),
-                        ).await;
-                        continue;
-                    }
-                    let paper = response.data.first().unwrap().clone();
-                    return Ok(paper);
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(
-                        wait_time,
-                        format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                    ).await;
-                    continue;
-                }
-            }
-        }
+        let url = self.get_url(Endpoint::GetAPaperByTitle, &mut query_params)?;
+        let response: PaperIds = self
+            .execute(Endpoint::GetAPaperByTitle, request::Method::GET, &url, None)
+            .await?;
+        response
+            .data
+            .into_iter()
+            .next()
+            .ok_or(Error::EmptyResponse {
+                endpoint: Endpoint::GetAPaperByTitle,
+                url,
+            })
     }
 
     /// # Description
     /// Retrieve details of a single paper based on the paper id.
     /// Make sure to provide the `paper_id` in the `query_params`.
+    /// Any [`PaperId`] or raw id it can parse (bare DOI, arXiv URL, ...) is accepted.
     /// For details of 'query_params', see: [`QueryParams`].
     ///
     /// # Example
@@ -669,187 +1145,65 @@ impl SemanticScholar {
     ///     PaperField::ReferenceCount,
     ///     PaperField::Year,
     /// ]);
-    /// let paper_details = ss.query_paper_details(query_params, 5, 10).await.unwrap();
+    /// let paper_details = ss.query_paper_details(query_params).await.unwrap();
     ///
     /// let title = paper_details.title.clone().unwrap();
     /// assert_eq!(title.to_lowercase(), "attention is all you need".to_string());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn query_paper_details(
-        &mut self,
-        query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
-    ) -> Result<Paper> {
+    pub async fn query_paper_details(&mut self, query_params: QueryParams) -> Result<Paper> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
 
-        let mut fields = query_params.fields.clone().unwrap_or_default();
-        if !fields.contains(&PaperField::PaperId) {
-            fields.push(PaperField::PaperId);
-            query_params.fields = Some(fields);
-        }
-
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
+        query_params.require_paper_id();
 
-        let url = self.get_url(Endpoint::GetPaperDetails, &mut query_params);
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get paper details: {}",
-                    query_params.paper_id
-                )));
-            }
-            let body = client.get(url.clone()).send().await?.text().await?;
-            match serde_json::from_str::<Paper>(&body) {
-                Ok(response) => {
-                    return Ok(response);
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(
-                        wait_time,
-                        format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                    ).await;
-                    continue;
-                }
-            }
-        }
+        let url = self.get_url(Endpoint::GetPaperDetails, &mut query_params)?;
+        self.execute(Endpoint::GetPaperDetails, request::Method::GET, &url, None)
+            .await
     }
 
+    /// # Description
+    /// Get one page of the papers citing a paper, each as a [`Citation`] holding the `citing_paper`.
+    /// Available fields for `fields: Vec<PaperField>`, see: [`PaperField`].
+    /// See for more details: [Details about a paper's citations](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_citations)
     pub async fn query_paper_citations(
         &mut self,
         query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
-    ) -> Result<ResponsePapers> {
+    ) -> Result<CitationsResponse> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
-
-        let mut fields = query_params.fields.clone().unwrap_or_default();
-        if !fields.contains(&PaperField::PaperId) {
-            fields.push(PaperField::PaperId);
-            query_params.fields = Some(fields);
-        }
-
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
 
-        let url = self.get_url(Endpoint::GetCitationsOfAPaper, &mut query_params);
+        query_params.require_paper_id();
 
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get paper citations: {}",
-                    query_params.paper_id
-                )));
-            }
-            match client.get(url.clone()).send().await {
-                Ok(response) => {
-                    let body = response.text().await?;
-                    match serde_json::from_str::<ResponsePapers>(&body) {
-                        Ok(response) => {
-                            return Ok(response);
-                        }
-                        Err(e) => {
-                            max_retry_count -= 1;
-                            self.sleep(
-                                wait_time,
-                                format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                            ).await;
-                            continue;
-                        }
-                    }
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(wait_time, &e.to_string()).await;
-                    continue;
-                }
-            }
-        }
+        let url = self.get_url(Endpoint::GetCitationsOfAPaper, &mut query_params)?;
+        self.execute(
+            Endpoint::GetCitationsOfAPaper,
+            request::Method::GET,
+            &url,
+            None,
+        )
+        .await
     }
 
+    /// # Description
+    /// Get one page of the papers cited by a paper, each as a [`Reference`] holding the `cited_paper`.
+    /// Available fields for `fields: Vec<PaperField>`, see: [`PaperField`].
+    /// See for more details: [Details about a paper's references](https://api.semanticscholar.org/api-docs/#tag/Paper-Data/operation/get_graph_get_paper_references)
     pub async fn query_paper_references(
         &mut self,
         query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
-    ) -> Result<ResponsePapers> {
+    ) -> Result<ReferencesResponse> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
-
-        let mut fields = query_params.fields.clone().unwrap_or_default();
-        if !fields.contains(&PaperField::PaperId) {
-            fields.push(PaperField::PaperId);
-            query_params.fields = Some(fields);
-        }
 
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
+        query_params.require_paper_id();
 
-        let url = self.get_url(Endpoint::GetReferencesOfAPaper, &mut query_params);
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get paper references: {}",
-                    query_params.paper_id
-                )));
-            }
-
-            match client.get(url.clone()).send().await {
-                Ok(response) => {
-                    let body = response.text().await?;
-                    match serde_json::from_str::<ResponsePapers>(&body) {
-                        Ok(response) => {
-                            return Ok(response);
-                        }
-                        Err(e) => {
-                            max_retry_count -= 1;
-                            self.sleep(
-                                wait_time,
-                                format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                            ).await;
-                            continue;
-                        }
-                    }
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(wait_time, &e.to_string()).await;
-                    continue;
-                }
-            }
-        }
+        let url = self.get_url(Endpoint::GetReferencesOfAPaper, &mut query_params)?;
+        self.execute(
+            Endpoint::GetReferencesOfAPaper,
+            request::Method::GET,
+            &url,
+            None,
+        )
+        .await
     }
 
     /// # Description
@@ -874,55 +1228,60 @@ impl SemanticScholar {
     ///     AuthorField::CitationCount,
     ///     AuthorField::HIndex,
     /// ]);
-    /// let author = ss.query_author_details(query_params, 5, 10).await.unwrap();
+    /// let author = ss.query_author_details(query_params).await.unwrap();
     /// assert!(author.name.is_some());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn query_author_details(
-        &mut self,
-        query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
-    ) -> Result<Author> {
+    pub async fn query_author_details(&mut self, query_params: QueryParams) -> Result<Author> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
 
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
+        let url = self.get_url(Endpoint::GetAuthorDetails, &mut query_params)?;
+        self.execute(Endpoint::GetAuthorDetails, request::Method::GET, &url, None)
+            .await
+    }
 
-        let url = self.get_url(Endpoint::GetAuthorDetails, &mut query_params);
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get author details: {}",
-                    query_params.paper_id
-                )));
-            }
-            let body = client.get(url.clone()).send().await?.text().await?;
-            match serde_json::from_str::<Author>(&body) {
-                Ok(response) => {
-                    return Ok(response);
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(
-                        wait_time,
-                        format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                    ).await;
-                    continue;
-                }
-            }
-        }
+    /// # Description
+    /// Get details for multiple authors at once.
+    /// Ids are sent in chunks of [`MAX_AUTHOR_BATCH_SIZE`]; the result is aligned with `author_ids`,
+    /// with `None` for ids the API does not know.
+    /// Available fields for `author_fields: Vec<AuthorField>`, see: [`AuthorField`].
+    /// See for more details: [Get details for multiple authors at once](https://api.semanticscholar.org/api-docs/#tag/Author-Data/operation/post_graph_get_authors)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use ss_tools::SemanticScholar;
+    /// # use ss_tools::structs::AuthorField;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let author_ids = vec!["1741101", "1780531", "0"];
+    /// let author_fields = vec![AuthorField::Name, AuthorField::HIndex];
+    /// let mut ss = SemanticScholar::new();
+    /// let authors = ss.bulk_query_authors(author_ids, author_fields).await?;
+    ///
+    /// assert_eq!(authors.len(), 3);
+    /// assert!(authors[0].is_some());
+    /// assert!(authors[2].is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bulk_query_authors(
+        &mut self,
+        author_ids: Vec<&str>,
+        author_fields: Vec<AuthorField>,
+    ) -> Result<Vec<Option<Author>>> {
+        let mut query_params = QueryParams::default();
+        query_params.author_fields(author_fields);
+        let url = self.get_url(Endpoint::GetMultipleAuthorDetails, &mut query_params)?;
+        self.execute_batch(
+            Endpoint::GetMultipleAuthorDetails,
+            &url,
+            &author_ids,
+            MAX_AUTHOR_BATCH_SIZE,
+        )
+        .await
     }
 
     /// # Description
@@ -946,7 +1305,7 @@ impl SemanticScholar {
     ///     AuthorField::PaperCount,
     ///     AuthorField::CitationCount,
     /// ]);
-    /// let response = ss.search_authors(query_params, 5, 10).await.unwrap();
+    /// let response = ss.search_authors(query_params).await.unwrap();
     /// assert!(!response.data.is_empty());
     /// # Ok(())
     /// # }
@@ -954,53 +1313,12 @@ impl SemanticScholar {
     pub async fn search_authors(
         &mut self,
         query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
     ) -> Result<AuthorSearchResponse> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
 
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
-
-        let url = self.get_url(Endpoint::SearchAuthors, &mut query_params);
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg("Failed to search authors"));
-            }
-            match client.get(url.clone()).send().await {
-                Ok(response) => {
-                    let body = response.text().await?;
-                    match serde_json::from_str::<AuthorSearchResponse>(&body) {
-                        Ok(response) => {
-                            return Ok(response);
-                        }
-                        Err(e) => {
-                            max_retry_count -= 1;
-                            self.sleep(
-                                wait_time,
-                                format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                            ).await;
-                            continue;
-                        }
-                    }
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(wait_time, &e.to_string()).await;
-                    continue;
-                }
-            }
-        }
+        let url = self.get_url(Endpoint::SearchAuthors, &mut query_params)?;
+        self.execute(Endpoint::SearchAuthors, request::Method::GET, &url, None)
+            .await
     }
 
     /// # Description
@@ -1025,7 +1343,7 @@ impl SemanticScholar {
     ///     PaperField::CitationCount,
     /// ]);
     /// query_params.limit(10);
-    /// let response = ss.query_author_papers(query_params, 5, 10).await.unwrap();
+    /// let response = ss.query_author_papers(query_params).await.unwrap();
     /// assert!(!response.data.is_empty());
     /// # Ok(())
     /// # }
@@ -1033,62 +1351,14 @@ impl SemanticScholar {
     pub async fn query_author_papers(
         &mut self,
         query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
     ) -> Result<AuthorPapersResponse> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
 
-        let mut fields = query_params.fields.clone().unwrap_or_default();
-        if !fields.contains(&PaperField::PaperId) {
-            fields.push(PaperField::PaperId);
-            query_params.fields = Some(fields);
-        }
+        query_params.require_paper_id();
 
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
-
-        let url = self.get_url(Endpoint::GetAuthorPapers, &mut query_params);
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get author papers: {}",
-                    query_params.paper_id
-                )));
-            }
-            match client.get(url.clone()).send().await {
-                Ok(response) => {
-                    let body = response.text().await?;
-                    match serde_json::from_str::<AuthorPapersResponse>(&body) {
-                        Ok(response) => {
-                            return Ok(response);
-                        }
-                        Err(e) => {
-                            max_retry_count -= 1;
-                            self.sleep(
-                                wait_time,
-                                format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                            ).await;
-                            continue;
-                        }
-                    }
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(wait_time, &e.to_string()).await;
-                    continue;
-                }
-            }
-        }
+        let url = self.get_url(Endpoint::GetAuthorPapers, &mut query_params)?;
+        self.execute(Endpoint::GetAuthorPapers, request::Method::GET, &url, None)
+            .await
     }
 
     /// # Description
@@ -1112,7 +1382,7 @@ impl SemanticScholar {
     ///     AuthorField::PaperCount,
     ///     AuthorField::CitationCount,
     /// ]);
-    /// let response = ss.query_paper_authors(query_params, 5, 10).await.unwrap();
+    /// let response = ss.query_paper_authors(query_params).await.unwrap();
     /// assert!(!response.data.is_empty());
     /// # Ok(())
     /// # }
@@ -1120,55 +1390,308 @@ impl SemanticScholar {
     pub async fn query_paper_authors(
         &mut self,
         query_params: QueryParams,
-        max_retry_count: u64,
-        wait_time: u64,
     ) -> Result<PaperAuthorsResponse> {
         let mut query_params = query_params.clone();
-        let mut max_retry_count = max_retry_count.clone();
 
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("user-agent", "ss-tools/0.1".parse().unwrap());
-        if !self.api_key.is_empty() {
-            headers.insert("x-api-key", self.api_key.parse().unwrap());
-        }
-        let client = request::Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap();
+        let url = self.get_url(Endpoint::GetPaperAuthors, &mut query_params)?;
+        self.execute(Endpoint::GetPaperAuthors, request::Method::GET, &url, None)
+            .await
+    }
 
-        let url = self.get_url(Endpoint::GetPaperAuthors, &mut query_params);
-        loop {
-            if max_retry_count == 0 {
-                return Err(Error::msg(format!(
-                    "Failed to get paper authors: {}",
-                    query_params.paper_id
-                )));
-            }
-            match client.get(url.clone()).send().await {
-                Ok(response) => {
-                    let body = response.text().await?;
-                    match serde_json::from_str::<PaperAuthorsResponse>(&body) {
-                        Ok(response) => {
-                            return Ok(response);
-                        }
-                        Err(e) => {
-                            max_retry_count -= 1;
-                            self.sleep(
-                                wait_time,
-                                format!("Error: {} Body: {}", &e.to_string(), &body).as_str(),
-                            ).await;
-                            continue;
-                        }
-                    }
-                }
-                Err(e) => {
-                    max_retry_count -= 1;
-                    self.sleep(wait_time, &e.to_string()).await;
-                    continue;
-                }
-            }
-        }
+    /// # Description
+    /// Stream every citation of a paper, following `next` page by page.
+    /// `query_params.offset` is the first offset and `query_params.limit` the page size (default and maximum: [`pagination::MAX_PAGE_SIZE`]).
+    /// The API does not page beyond [`pagination::MAX_OFFSET`] results; stop earlier with [`futures::StreamExt::take`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use futures::{StreamExt, TryStreamExt};
+    /// # use ss_tools::{SemanticScholar, QueryParams};
+    /// # use ss_tools::structs::PaperField;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ss = SemanticScholar::new();
+    /// let mut query_params = QueryParams::default();
+    /// query_params.paper_id("204e3073870fae3d05bcbc2f6a8e263d9b72e776");
+    /// query_params.fields(vec![PaperField::Title, PaperField::Year]);
+    /// let citations = ss
+    ///     .citations_stream(query_params)
+    ///     .take(2500)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// assert_eq!(citations.len(), 2500);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn citations_stream(
+        &self,
+        query_params: QueryParams,
+    ) -> impl Stream<Item = Result<Citation>> {
+        let mut query_params = query_params.clone();
+        query_params.require_paper_id();
+        paginate::<CitationsResponse>(self.clone(), Endpoint::GetCitationsOfAPaper, query_params)
+    }
+
+    /// # Description
+    /// Stream every reference of a paper, following `next` page by page.
+    /// Paging works as in [`SemanticScholar::citations_stream`].
+    pub fn references_stream(
+        &self,
+        query_params: QueryParams,
+    ) -> impl Stream<Item = Result<Reference>> {
+        let mut query_params = query_params.clone();
+        query_params.require_paper_id();
+        paginate::<ReferencesResponse>(self.clone(), Endpoint::GetReferencesOfAPaper, query_params)
+    }
+
+    /// # Description
+    /// Stream every paper of an author, following `next` page by page.
+    /// Paging works as in [`SemanticScholar::citations_stream`].
+    pub fn author_papers_stream(
+        &self,
+        query_params: QueryParams,
+    ) -> impl Stream<Item = Result<Paper>> {
+        let mut query_params = query_params.clone();
+        query_params.require_paper_id();
+        paginate::<AuthorPapersResponse>(self.clone(), Endpoint::GetAuthorPapers, query_params)
+    }
+
+    /// # Description
+    /// Stream every author of a paper, following `next` page by page.
+    /// Paging works as in [`SemanticScholar::citations_stream`].
+    pub fn paper_authors_stream(
+        &self,
+        query_params: QueryParams,
+    ) -> impl Stream<Item = Result<Author>> {
+        paginate::<PaperAuthorsResponse>(self.clone(), Endpoint::GetPaperAuthors, query_params)
+    }
+
+    /// # Description
+    /// Stream every author matching `query_params.query_text`, following `next` page by page.
+    /// Paging works as in [`SemanticScholar::citations_stream`].
+    pub fn search_authors_stream(
+        &self,
+        query_params: QueryParams,
+    ) -> impl Stream<Item = Result<Author>> {
+        paginate::<AuthorSearchResponse>(self.clone(), Endpoint::SearchAuthors, query_params)
+    }
+
+    /// # Description
+    /// Recommend papers similar to a single seed paper, using the Recommendations API.
+    /// `pool` selects the candidate papers, see [`RecommendationPool`]; `limit` is at most 500.
+    /// Available fields for `fields: Vec<PaperField>`, see: [`PaperField`].
+    /// See for more details: [Get recommended papers for a single positive example paper](https://api.semanticscholar.org/api-docs/recommendations#tag/Paper-Recommendations/operation/get_papers_for_paper)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use ss_tools::SemanticScholar;
+    /// # use ss_tools::structs::{PaperField, RecommendationPool};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::new();
+    /// let papers = ss
+    ///     .recommend_for_paper(
+    ///         "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+    ///         RecommendationPool::AllCs,
+    ///         10,
+    ///         vec![PaperField::Title, PaperField::Year],
+    ///     )
+    ///     .await?;
+    /// assert!(!papers.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recommend_for_paper(
+        &mut self,
+        paper_id: impl ToString,
+        pool: RecommendationPool,
+        limit: u64,
+        fields: Vec<PaperField>,
+    ) -> Result<Vec<Paper>> {
+        let mut query_params = QueryParams::default();
+        query_params
+            .paper_id(paper_id)
+            .pool(pool)
+            .limit(limit)
+            .fields(fields);
+        let url = self.get_url(Endpoint::GetRecommendationsForPaper, &mut query_params)?;
+        let response: RecommendationsResponse = self
+            .execute(
+                Endpoint::GetRecommendationsForPaper,
+                request::Method::GET,
+                &url,
+                None,
+            )
+            .await?;
+        Ok(response.recommended_papers)
+    }
+
+    /// # Description
+    /// Recommend papers similar to `positive_paper_ids` and unlike `negative_paper_ids`, using the Recommendations API.
+    /// `limit` is at most 500.
+    /// Available fields for `fields: Vec<PaperField>`, see: [`PaperField`].
+    /// See for more details: [Get recommended papers for lists of positive and negative example papers](https://api.semanticscholar.org/api-docs/recommendations#tag/Paper-Recommendations/operation/post_papers)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use ss_tools::SemanticScholar;
+    /// # use ss_tools::structs::PaperField;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::new();
+    /// let papers = ss
+    ///     .recommend_from_seeds(
+    ///         vec!["204e3073870fae3d05bcbc2f6a8e263d9b72e776", "ARXIV:1810.04805"],
+    ///         vec!["ARXIV:1512.03385"],
+    ///         10,
+    ///         vec![PaperField::Title, PaperField::Year],
+    ///     )
+    ///     .await?;
+    /// assert!(!papers.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recommend_from_seeds(
+        &mut self,
+        positive_paper_ids: Vec<&str>,
+        negative_paper_ids: Vec<&str>,
+        limit: u64,
+        fields: Vec<PaperField>,
+    ) -> Result<Vec<Paper>> {
+        let mut query_params = QueryParams::default();
+        query_params.limit(limit).fields(fields);
+        let url = self.get_url(Endpoint::GetRecommendationsFromSeeds, &mut query_params)?;
+        let normalize =
+            |ids: Vec<&str>| -> Vec<String> { ids.into_iter().map(normalize_paper_id).collect() };
+        let body = serde_json::json!({
+            "positivePaperIds": normalize(positive_paper_ids),
+            "negativePaperIds": normalize(negative_paper_ids),
+        })
+        .to_string();
+        let response: RecommendationsResponse = self
+            .execute(
+                Endpoint::GetRecommendationsFromSeeds,
+                request::Method::POST,
+                &url,
+                Some(&body),
+            )
+            .await?;
+        Ok(response.recommended_papers)
+    }
+
+    /// # Description
+    /// List the ids of every Semantic Scholar Academic Graph release, oldest first.
+    /// See for more details: [List releases](https://api.semanticscholar.org/api-docs/datasets#tag/Release-Data/operation/get_releases)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyhow::Result;
+    /// # use ss_tools::SemanticScholar;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::new();
+    /// let releases = ss.list_releases().await?;
+    /// println!("latest release: {:?}", releases.last());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_releases(&mut self) -> Result<Vec<String>> {
+        let url = format!("{}/release/", self.datasets_base_url);
+        self.execute(Endpoint::ListReleases, request::Method::GET, &url, None)
+            .await
+    }
+
+    /// # Description
+    /// Get the datasets of a release. `release_id` is a date such as `2024-01-02` or `latest`.
+    /// See for more details: [Datasets in a release](https://api.semanticscholar.org/api-docs/datasets#tag/Release-Data/operation/get_release)
+    pub async fn get_release(&mut self, release_id: &str) -> Result<Release> {
+        let url = format!(
+            "{}/release/{}",
+            self.datasets_base_url,
+            encode_path_segment(release_id)
+        );
+        self.execute(Endpoint::GetRelease, request::Method::GET, &url, None)
+            .await
+    }
+
+    /// # Description
+    /// Get a dataset of a release with the pre-signed URLs of its files. Requires an API key.
+    /// See for more details: [Download links for a dataset](https://api.semanticscholar.org/api-docs/datasets#tag/Release-Data/operation/get_dataset)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use anyhow::Result;
+    /// # use ss_tools::SemanticScholar;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::builder().api_key("xxxxxxxx").build()?;
+    /// let dataset = ss.get_dataset("latest", "papers").await?;
+    /// for url in &dataset.files {
+    ///     println!("{}", url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_dataset(&mut self, release_id: &str, dataset_name: &str) -> Result<Dataset> {
+        let url = format!(
+            "{}/release/{}/dataset/{}",
+            self.datasets_base_url,
+            encode_path_segment(release_id),
+            encode_path_segment(dataset_name)
+        );
+        self.execute(Endpoint::GetDataset, request::Method::GET, &url, None)
+            .await
+    }
+
+    /// # Description
+    /// Get the chain of diffs that brings `dataset_name` from `start_release_id` to `end_release_id`
+    /// (which may be `latest`). Apply the diffs in order: each one lists pre-signed URLs of files
+    /// with records to upsert and records to delete. Requires an API key.
+    /// See for more details: [Incremental diffs between releases](https://api.semanticscholar.org/api-docs/datasets#tag/Incremental-Updates/operation/get_diff)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use anyhow::Result;
+    /// # use ss_tools::SemanticScholar;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let mut ss = SemanticScholar::builder().api_key("xxxxxxxx").build()?;
+    /// let diffs = ss.get_dataset_diffs("2024-01-02", "latest", "papers").await?;
+    /// for diff in &diffs.diffs {
+    ///     println!(
+    ///         "{} -> {}: {} update files, {} delete files",
+    ///         diff.from_release,
+    ///         diff.to_release,
+    ///         diff.update_files.len(),
+    ///         diff.delete_files.len()
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_dataset_diffs(
+        &mut self,
+        start_release_id: &str,
+        end_release_id: &str,
+        dataset_name: &str,
+    ) -> Result<DatasetDiffs> {
+        let url = format!(
+            "{}/diffs/{}/to/{}/{}",
+            self.datasets_base_url,
+            encode_path_segment(start_release_id),
+            encode_path_segment(end_release_id),
+            encode_path_segment(dataset_name)
+        );
+        self.execute(Endpoint::GetDatasetDiffs, request::Method::GET, &url, None)
+            .await
     }
 }
@@ -0,0 +1,149 @@
+use crate::error::Result;
+use crate::structs::*;
+use crate::{QueryParams, SemanticScholar};
+use futures::stream::{self, Stream, TryStreamExt};
+use reqwest as request;
+use serde::de::DeserializeOwned;
+
+/// Largest `limit` the API accepts for a single page.
+pub const MAX_PAGE_SIZE: u64 = 1000;
+/// `offset + limit` may not exceed this value; results beyond it cannot be paged through.
+pub const MAX_OFFSET: u64 = 10_000;
+
+/// A single page of an offset-paginated endpoint.
+pub(crate) trait Page: DeserializeOwned {
+    type Item;
+
+    fn next_offset(&self) -> Option<u64>;
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl Page for CitationsResponse {
+    type Item = Citation;
+
+    fn next_offset(&self) -> Option<u64> {
+        self.next
+    }
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data
+    }
+}
+
+impl Page for ReferencesResponse {
+    type Item = Reference;
+
+    fn next_offset(&self) -> Option<u64> {
+        self.next
+    }
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data
+    }
+}
+
+impl Page for AuthorSearchResponse {
+    type Item = Author;
+
+    fn next_offset(&self) -> Option<u64> {
+        self.next
+    }
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data
+    }
+}
+
+impl Page for AuthorPapersResponse {
+    type Item = Paper;
+
+    fn next_offset(&self) -> Option<u64> {
+        self.next
+    }
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data
+    }
+}
+
+impl Page for PaperAuthorsResponse {
+    type Item = Author;
+
+    fn next_offset(&self) -> Option<u64> {
+        self.next
+    }
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data
+    }
+}
+
+/// `(offset, limit)` of the page starting at `offset`, or `None` once the offset ceiling is reached.
+pub(crate) fn page_window(offset: u64, page_size: u64) -> Option<(u64, u64)> {
+    if offset >= MAX_OFFSET {
+        return None;
+    }
+    let limit = page_size.clamp(1, MAX_PAGE_SIZE).min(MAX_OFFSET - offset);
+    Some((offset, limit))
+}
+
+/// Stream the items of every page of `endpoint`, following `next` until the API stops
+/// returning one or [`MAX_OFFSET`] is reached.
+///
+/// `query_params.offset` is the first offset and `query_params.limit` the page size
+/// (default [`MAX_PAGE_SIZE`]).
+pub(crate) fn paginate<P>(
+    ss: SemanticScholar,
+    endpoint: Endpoint,
+    query_params: QueryParams,
+) -> impl Stream<Item = Result<P::Item>>
+where
+    P: Page + 'static,
+    P::Item: 'static,
+{
+    let page_size = query_params.limit.unwrap_or(MAX_PAGE_SIZE);
+    let start = query_params.offset.unwrap_or(0);
+    stream::try_unfold(
+        (ss, endpoint, query_params, Some(start)),
+        move |(ss, endpoint, mut query_params, offset)| async move {
+            let Some((offset, limit)) = offset.and_then(|offset| page_window(offset, page_size))
+            else {
+                return Ok(None);
+            };
+            query_params.offset(offset).limit(limit);
+            let url = ss.get_url(endpoint.clone(), &mut query_params)?;
+            let page: P = ss
+                .execute(endpoint.clone(), request::Method::GET, &url, None)
+                .await?;
+            let next = page.next_offset().filter(|next| *next > offset);
+            let items = page.into_items();
+            let next = if items.is_empty() { None } else { next };
+            Ok(Some((items, (ss, endpoint, query_params, next))))
+        },
+    )
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Stream the papers of every batch of a token-paginated search, following `token` until the
+/// API returns none.
+pub(crate) fn paginate_token(
+    ss: SemanticScholar,
+    endpoint: Endpoint,
+    query_params: QueryParams,
+) -> impl Stream<Item = Result<Paper>> {
+    stream::try_unfold(
+        (ss, endpoint, query_params, true),
+        |(ss, endpoint, mut query_params, more)| async move {
+            if !more {
+                return Ok(None);
+            }
+            let url = ss.get_url(endpoint.clone(), &mut query_params)?;
+            let batch: PaperIds = ss
+                .execute(endpoint.clone(), request::Method::GET, &url, None)
+                .await?;
+            let more = !batch.token.is_empty() && !batch.data.is_empty();
+            if more {
+                query_params.token(&batch.token);
+            }
+            Ok(Some((batch.data, (ss, endpoint, query_params, more))))
+        },
+    )
+    .map_ok(|papers| stream::iter(papers.into_iter().map(Ok)))
+    .try_flatten()
+}
@@ -0,0 +1,123 @@
+use crate::structs::Endpoint;
+use fxhash::FxHashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// A request budget: at most `requests` requests every `per`.
+///
+/// Semantic Scholar API keys are issued with a quota of 1 request per second across all
+/// endpoints, which is what [`SemanticScholar`](crate::SemanticScholar) uses by default when an
+/// API key is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self {
+            requests: requests.max(1),
+            per,
+        }
+    }
+
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+
+    fn interval(&self) -> Duration {
+        self.per / self.requests
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Token bucket limiter.
+///
+/// Clones share the same bucket, so one limiter throttles every task using the client.
+/// Waiters reserve their token up front, which keeps concurrent callers in FIFO order.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: limit.requests as f64,
+                updated_at: Instant::now(),
+            })),
+        }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Wait until a request may be sent.
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut bucket = self.bucket.lock().await;
+            let now = Instant::now();
+            let capacity = self.limit.requests as f64;
+            let refill = now.duration_since(bucket.updated_at).as_secs_f64()
+                / self.limit.interval().as_secs_f64();
+            bucket.tokens = (bucket.tokens + refill).min(capacity);
+            bucket.updated_at = now;
+            bucket.tokens -= 1.0;
+            if bucket.tokens >= 0.0 {
+                return;
+            }
+            self.limit.interval().mul_f64(-bucket.tokens)
+        };
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Rate limiters of a client: one default budget and optional per-endpoint budgets.
+///
+/// An endpoint with its own budget is throttled by that budget only.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RateLimiters {
+    default: Option<RateLimiter>,
+    endpoints: FxHashMap<Endpoint, RateLimiter>,
+}
+
+impl RateLimiters {
+    pub(crate) fn new(
+        default: Option<RateLimit>,
+        endpoints: &FxHashMap<Endpoint, RateLimit>,
+    ) -> Self {
+        Self {
+            default: default.map(RateLimiter::new),
+            endpoints: endpoints
+                .iter()
+                .map(|(endpoint, limit)| (endpoint.clone(), RateLimiter::new(*limit)))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn get(&self, endpoint: &Endpoint) -> Option<&RateLimiter> {
+        self.endpoints.get(endpoint).or(self.default.as_ref())
+    }
+
+    pub(crate) async fn acquire(&self, endpoint: &Endpoint) {
+        if let Some(limiter) = self.get(endpoint) {
+            limiter.acquire().await;
+        }
+    }
+}
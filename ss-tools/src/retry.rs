@@ -0,0 +1,141 @@
+use crate::error::Error;
+use std::time::{Duration, SystemTime};
+
+/// How [`SemanticScholar`](crate::SemanticScholar) retries failed requests.
+///
+/// By default only rate limiting (429), server errors (5xx) and timeouts are retried, with
+/// exponential backoff and jitter. When the API sends a `Retry-After` header, its delay (capped
+/// at [`RetryPolicy::max_backoff`]) is used instead of the computed backoff. Deterministic failures such as an unknown paper id
+/// (404) or an invalid field (400) are returned immediately.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyhow::Result;
+/// # use ss_tools::{RetryPolicy, SemanticScholar};
+/// # use std::time::Duration;
+/// # fn main() -> Result<()> {
+/// let mut policy = RetryPolicy::default();
+/// policy
+///     .max_attempts(8)
+///     .initial_backoff(Duration::from_millis(500))
+///     .max_backoff(Duration::from_secs(30));
+/// let ss = SemanticScholar::builder().retry_policy(policy).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: f64,
+    respect_retry_after: bool,
+    retry_on: fn(&Error) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 0.2,
+            respect_retry_after: true,
+            retry_on: RetryPolicy::is_transient,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request exactly once.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Total number of attempts, including the first one. `0` is treated as `1`.
+    pub fn max_attempts(&mut self, max_attempts: u32) -> &mut Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+    /// Delay before the first retry.
+    pub fn initial_backoff(&mut self, initial_backoff: Duration) -> &mut Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+    /// Upper bound of the computed backoff and of a `Retry-After` delay.
+    pub fn max_backoff(&mut self, max_backoff: Duration) -> &mut Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+    /// Growth factor of the backoff between consecutive retries.
+    pub fn multiplier(&mut self, multiplier: f64) -> &mut Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+    /// Random spread applied to the backoff, as a fraction of it (`0.2` means ±20%).
+    pub fn jitter(&mut self, jitter: f64) -> &mut Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+    pub fn respect_retry_after(&mut self, respect_retry_after: bool) -> &mut Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+    /// Replace the predicate deciding which errors are retried (default: [`RetryPolicy::is_transient`]).
+    pub fn retry_on(&mut self, retry_on: fn(&Error) -> bool) -> &mut Self {
+        self.retry_on = retry_on;
+        self
+    }
+
+    /// `true` for 429, 5xx and timeouts.
+    pub fn is_transient(error: &Error) -> bool {
+        matches!(
+            error,
+            Error::RateLimited(_) | Error::Server(_) | Error::Timeout { .. }
+        )
+    }
+
+    /// Whether a request that failed with `error` on attempt `attempt` (1-based) is tried again.
+    pub fn should_retry(&self, attempt: u32, error: &Error) -> bool {
+        attempt < self.max_attempts && (self.retry_on)(error)
+    }
+
+    /// Backoff before retry number `attempt` (1-based), without jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(32) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::from_secs_f64(backoff.min(self.max_backoff.as_secs_f64()))
+    }
+
+    /// Delay before retrying after `error` on attempt `attempt` (1-based).
+    pub fn delay(&self, attempt: u32, error: &Error) -> Duration {
+        if self.respect_retry_after {
+            if let Some(retry_after) = error.api_error().and_then(|e| e.retry_after) {
+                return retry_after.min(self.max_backoff);
+            }
+        }
+        let backoff = self.backoff(attempt).as_secs_f64();
+        let spread = backoff * self.jitter * (fastrand::f64() * 2.0 - 1.0);
+        Duration::from_secs_f64((backoff + spread).max(0.0))
+    }
+}
+
+/// Delay requested by a `Retry-After` header, given either in seconds or as an HTTP date.
+/// A date in the past means no delay.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
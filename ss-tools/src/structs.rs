@@ -1,4 +1,9 @@
+use crate::dates::PartialDate;
+use crate::error::Error;
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
 
 /// Deserialize `null` as `T::default()` (e.g. empty `Vec`).
 ///
@@ -14,7 +19,284 @@ where
     Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
 }
 
-#[derive(Clone, Debug, PartialEq, Default)]
+/// Semantic Scholar corpus id, the numeric primary key of a paper.
+///
+/// Corpus ids are already above 270 million, so they are stored as `u64`.
+/// The Graph API sends them as numbers (`"corpusId": 215416146`), while the bulk
+/// datasets store external ids as strings (`"CorpusId": "215416146"`); both are accepted.
+///
+/// # Example
+///
+/// ```rust
+/// # use ss_tools::structs::CorpusId;
+/// let id: CorpusId = serde_json::from_str("\"215416146\"").unwrap();
+/// assert_eq!(id, CorpusId(215416146));
+/// assert_eq!(id.to_string(), "215416146");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct CorpusId(pub u64);
+
+impl CorpusId {
+    pub fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for CorpusId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for CorpusId {
+    fn from(id: u64) -> Self {
+        CorpusId(id)
+    }
+}
+
+impl From<u32> for CorpusId {
+    fn from(id: u32) -> Self {
+        CorpusId(u64::from(id))
+    }
+}
+
+impl PartialEq<u64> for CorpusId {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<CorpusId> for u64 {
+    fn from(id: CorpusId) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for CorpusId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse().map(CorpusId)
+    }
+}
+
+impl<'de> Deserialize<'de> for CorpusId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CorpusIdVisitor;
+
+        impl serde::de::Visitor<'_> for CorpusIdVisitor {
+            type Value = CorpusId;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a corpus id as an integer or a string")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<CorpusId, E> {
+                Ok(CorpusId(v))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<CorpusId, E> {
+                u64::try_from(v).map(CorpusId).map_err(E::custom)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<CorpusId, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(CorpusIdVisitor)
+    }
+}
+
+/// Any identifier the Graph API accepts for a paper.
+///
+/// [`Display`](fmt::Display) renders the prefixed form the API expects (`ARXIV:2106.15928`,
+/// `DOI:10.18653/v1/n18-3011`, `CorpusId:215416146`, ...). Parsing accepts those forms as well
+/// as raw identifiers: bare DOIs, (versioned) arXiv ids, and arXiv, doi.org and
+/// semanticscholar.org URLs. Parsed ids are normalized: DOIs and paper SHAs are lowercased
+/// and arXiv versions are dropped.
+///
+/// # Example
+///
+/// ```rust
+/// # use ss_tools::structs::PaperId;
+/// let id: PaperId = "https://arxiv.org/abs/2106.15928v2".parse().unwrap();
+/// assert_eq!(id, PaperId::Arxiv("2106.15928".to_string()));
+/// assert_eq!(id.to_string(), "ARXIV:2106.15928");
+///
+/// let id: PaperId = "10.18653/V1/N18-3011".parse().unwrap();
+/// assert_eq!(id.to_string(), "DOI:10.18653/v1/n18-3011");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaperId {
+    /// The 40 character Semantic Scholar paper id.
+    Sha(String),
+    CorpusId(CorpusId),
+    Doi(String),
+    Arxiv(String),
+    Mag(String),
+    Acl(String),
+    Pmid(String),
+    Pmcid(String),
+    /// A URL from one of the sites the API recognizes (arXiv, ACL Anthology, ACM, bioRxiv, ...).
+    Url(String),
+}
+
+impl fmt::Display for PaperId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaperId::Sha(sha) => write!(f, "{}", sha),
+            PaperId::CorpusId(id) => write!(f, "CorpusId:{}", id),
+            PaperId::Doi(doi) => write!(f, "DOI:{}", doi),
+            PaperId::Arxiv(id) => write!(f, "ARXIV:{}", id),
+            PaperId::Mag(id) => write!(f, "MAG:{}", id),
+            PaperId::Acl(id) => write!(f, "ACL:{}", id),
+            PaperId::Pmid(id) => write!(f, "PMID:{}", id),
+            PaperId::Pmcid(id) => write!(f, "PMCID:{}", id),
+            PaperId::Url(url) => write!(f, "URL:{}", url),
+        }
+    }
+}
+
+impl From<CorpusId> for PaperId {
+    fn from(id: CorpusId) -> Self {
+        PaperId::CorpusId(id)
+    }
+}
+
+impl FromStr for PaperId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || Error::InvalidPaperId(s.to_string());
+        if is_url(s) {
+            return Ok(PaperId::from_url(s));
+        }
+        if let Some((prefix, id)) = s.split_once(':') {
+            let id = id.trim();
+            if id.is_empty() {
+                return Err(invalid());
+            }
+            return match prefix.trim().to_ascii_uppercase().as_str() {
+                "CORPUSID" => id.parse().map(PaperId::CorpusId).map_err(|_| invalid()),
+                "DOI" => Ok(PaperId::Doi(id.to_lowercase())),
+                "ARXIV" => Ok(PaperId::Arxiv(strip_arxiv_version(id).to_string())),
+                "MAG" => Ok(PaperId::Mag(id.to_string())),
+                "ACL" => Ok(PaperId::Acl(id.to_string())),
+                "PMID" => Ok(PaperId::Pmid(id.to_string())),
+                "PMCID" => Ok(PaperId::Pmcid(id.to_string())),
+                "URL" => Ok(PaperId::Url(id.to_string())),
+                _ => Err(invalid()),
+            };
+        }
+        if is_sha(s) {
+            Ok(PaperId::Sha(s.to_ascii_lowercase()))
+        } else if is_doi(s) {
+            Ok(PaperId::Doi(s.to_lowercase()))
+        } else if is_arxiv_id(s) {
+            Ok(PaperId::Arxiv(strip_arxiv_version(s).to_string()))
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+impl PaperId {
+    /// Turn a URL into the most specific id it contains, or keep it as [`PaperId::Url`].
+    fn from_url(url: &str) -> Self {
+        let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let without_query = without_scheme
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches('/');
+        let (host, path) = without_query.split_once('/').unwrap_or((without_query, ""));
+        let host = host.to_ascii_lowercase();
+        let host = host.strip_prefix("www.").unwrap_or(&host);
+        match host {
+            "semanticscholar.org" | "api.semanticscholar.org" => {
+                let last = path.rsplit('/').next().unwrap_or_default();
+                if let Some(sha) = path.split('/').find(|segment| is_sha(segment)) {
+                    return PaperId::Sha(sha.to_ascii_lowercase());
+                }
+                if let Some((prefix, id)) = last.split_once(':') {
+                    if prefix.eq_ignore_ascii_case("corpusid") {
+                        if let Ok(id) = id.parse() {
+                            return PaperId::CorpusId(id);
+                        }
+                    }
+                }
+            }
+            "arxiv.org" | "export.arxiv.org" => {
+                if let Some((_, id)) = path.split_once('/') {
+                    let id = id.strip_suffix(".pdf").unwrap_or(id);
+                    if is_arxiv_id(id) {
+                        return PaperId::Arxiv(strip_arxiv_version(id).to_string());
+                    }
+                }
+            }
+            "doi.org" | "dx.doi.org" if is_doi(path) => {
+                return PaperId::Doi(path.to_lowercase());
+            }
+            _ => {}
+        }
+        PaperId::Url(url.to_string())
+    }
+}
+
+fn is_url(s: &str) -> bool {
+    let lower = s.get(..8).unwrap_or(s).to_ascii_lowercase();
+    lower.starts_with("http://") || lower.starts_with("https://")
+}
+
+fn is_sha(s: &str) -> bool {
+    s.len() == 40 && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_doi(s: &str) -> bool {
+    s.starts_with("10.") && s.contains('/')
+}
+
+/// `2106.15928`, `2106.15928v2`, `hep-th/9901001` or `math.GT/0309136v1`.
+fn is_arxiv_id(s: &str) -> bool {
+    let s = strip_arxiv_version(s);
+    let all_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    if let Some((yymm, number)) = s.split_once('.') {
+        if yymm.len() == 4 && all_digits(yymm) && (4..=5).contains(&number.len()) {
+            return all_digits(number);
+        }
+    }
+    match s.split_once('/') {
+        Some((archive, number)) => {
+            let archive = archive.split('.').next().unwrap_or_default();
+            !archive.is_empty()
+                && archive.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+                && number.len() == 7
+                && all_digits(number)
+        }
+        None => false,
+    }
+}
+
+fn strip_arxiv_version(id: &str) -> &str {
+    match id.rsplit_once('v') {
+        Some((base, version))
+            if !version.is_empty()
+                && version.chars().all(|c| c.is_ascii_digit())
+                && base.ends_with(|c: char| c.is_ascii_digit()) =>
+        {
+            base
+        }
+        _ => id,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub enum Endpoint {
     #[default]
     GetMultiplePpaerDetails,
@@ -27,6 +309,16 @@ pub enum Endpoint {
     SearchAuthors,
     GetAuthorPapers,
     GetPaperAuthors,
+    SearchPapersInBulk,
+    AutocompletePapers,
+    GetMultipleAuthorDetails,
+    GetRecommendationsForPaper,
+    GetRecommendationsFromSeeds,
+    ListReleases,
+    GetRelease,
+    GetDataset,
+    GetDatasetDiffs,
+    SearchSnippets,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -40,11 +332,26 @@ pub enum AuthorField {
     PaperCount,
     CitationCount,
     HIndex,
+    ExternalIds,
+    Aliases,
+    Papers(Vec<PaperField>),
 }
 
-impl AuthorField {
-    pub fn to_string(&self) -> String {
-        match self {
+/// `prefix.field` for every nested field, or the bare `prefix` when there are none.
+fn nested_names<T: fmt::Display>(prefix: &str, fields: &[T]) -> String {
+    if fields.is_empty() {
+        return prefix.to_string();
+    }
+    fields
+        .iter()
+        .map(|field| format!("{}.{}", prefix, field))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+impl fmt::Display for AuthorField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
             AuthorField::AuthorId => "authorId".to_string(),
             AuthorField::Name => "name".to_string(),
             AuthorField::Url => "url".to_string(),
@@ -53,7 +360,11 @@ impl AuthorField {
             AuthorField::PaperCount => "paperCount".to_string(),
             AuthorField::CitationCount => "citationCount".to_string(),
             AuthorField::HIndex => "hIndex".to_string(),
-        }
+            AuthorField::ExternalIds => "externalIds".to_string(),
+            AuthorField::Aliases => "aliases".to_string(),
+            AuthorField::Papers(fields) => nested_names("papers", fields),
+        };
+        write!(f, "{}", s)
     }
 }
 
@@ -88,11 +399,14 @@ pub enum PaperField {
     IsInfluential,
     ContextsWithIntent,
     ExternalIds,
+    /// Not available from bulk search nor for nested `citations` / `references`.
+    Tldr,
+    TextAvailability,
 }
 
-impl PaperField {
-    pub fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for PaperField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
             PaperField::PaperId => "paperId".to_string(),
             PaperField::Corpusid => "corpusId".to_string(),
             PaperField::Url => "url".to_string(),
@@ -112,41 +426,354 @@ impl PaperField {
             PaperField::PublicationDate => "publicationDate".to_string(),
             PaperField::Journal => "journal".to_string(),
             PaperField::CitationStyles => "citationStyles".to_string(),
-            PaperField::Authors(fields) => {
-                let fields = fields
-                    .iter()
-                    .map(|field| format!("authors.{}", field.to_string()))
-                    .collect::<Vec<String>>()
-                    .join(",");
-                return fields;
-            }
-            PaperField::Citations(fields) => {
-                let fields = fields
-                    .iter()
-                    .map(|field| format!("citations.{}", field.to_string()))
-                    .collect::<Vec<String>>()
-                    .join(",");
-                return fields;
-            }
-            PaperField::References(fields) => {
-                let fields = fields
-                    .iter()
-                    .map(|field| format!("references.{}", field.to_string()))
-                    .collect::<Vec<String>>()
-                    .join(",");
-                return fields;
-            }
+            PaperField::Authors(fields) => nested_names("authors", fields),
+            PaperField::Citations(fields) => nested_names("citations", fields),
+            PaperField::References(fields) => nested_names("references", fields),
             PaperField::Embedding => "embedding.specter_v2".to_string(),
             PaperField::Contexts => "contexts".to_string(),
             PaperField::Intents => "intents".to_string(),
             PaperField::IsInfluential => "isInfluential".to_string(),
             PaperField::ContextsWithIntent => "contextsWithIntent".to_string(),
             PaperField::ExternalIds => "externalIds".to_string(),
+            PaperField::Tldr => "tldr".to_string(),
+            PaperField::TextAvailability => "textAvailability".to_string(),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl PaperField {
+    /// Fields describing a citation edge rather than a paper.
+    fn is_citation_edge_field(&self) -> bool {
+        matches!(
+            self,
+            PaperField::Contexts
+                | PaperField::Intents
+                | PaperField::IsInfluential
+                | PaperField::ContextsWithIntent
+        )
+    }
+}
+
+/// Fields of papers nested under `prefix` (`citations`, `references`, `papers`) that the API
+/// does not return, e.g. `citations.tldr`.
+fn unsupported_nested_fields(prefix: &str, fields: &[PaperField]) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|field| {
+            field.is_citation_edge_field()
+                || matches!(
+                    field,
+                    PaperField::Tldr | PaperField::Citations(_) | PaperField::References(_)
+                )
+        })
+        .map(|field| format!("{}.{}", prefix, field))
+        .collect()
+}
+
+impl Endpoint {
+    /// `false` for the endpoints without a `fields` parameter: autocomplete and the Datasets API.
+    pub fn takes_fields(&self) -> bool {
+        !matches!(
+            self,
+            Endpoint::AutocompletePapers
+                | Endpoint::ListReleases
+                | Endpoint::GetRelease
+                | Endpoint::GetDataset
+                | Endpoint::GetDatasetDiffs
+        )
+    }
+
+    /// Fields of `fields` this endpoint rejects, as they appear in the `fields` parameter.
+    ///
+    /// - `contexts`, `intents`, `isInfluential` and `contextsWithIntent` are only returned by the
+    ///   citations and references endpoints.
+    /// - `embedding` is not available from paper searches.
+    /// - `tldr`, `citations` and `references` are not available from bulk search.
+    /// - Papers nested in `citations`, `references` or the citations and references endpoints
+    ///   cannot have their own `citations`, `references`, `tldr` or edge fields.
+    pub fn unsupported_fields(&self, fields: &[PaperField]) -> Vec<String> {
+        let returns_edges = matches!(
+            self,
+            Endpoint::GetCitationsOfAPaper | Endpoint::GetReferencesOfAPaper
+        );
+        let is_search = matches!(
+            self,
+            Endpoint::GetPapersByTitle | Endpoint::GetAPaperByTitle | Endpoint::SearchPapersInBulk
+        );
+        let is_bulk_search = *self == Endpoint::SearchPapersInBulk;
+        let mut unsupported = Vec::new();
+        for field in fields {
+            match field {
+                field if field.is_citation_edge_field() && !returns_edges => {
+                    unsupported.push(field.to_string())
+                }
+                PaperField::Embedding if is_search => unsupported.push(field.to_string()),
+                PaperField::Tldr if is_bulk_search => unsupported.push(field.to_string()),
+                PaperField::Citations(_) | PaperField::References(_)
+                    if is_bulk_search || returns_edges =>
+                {
+                    unsupported.push(field.to_string())
+                }
+                PaperField::Citations(nested) => {
+                    unsupported.extend(unsupported_nested_fields("citations", nested))
+                }
+                PaperField::References(nested) => {
+                    unsupported.extend(unsupported_nested_fields("references", nested))
+                }
+                PaperField::Authors(nested) => unsupported.extend(
+                    nested
+                        .iter()
+                        .filter(|field| matches!(field, AuthorField::Papers(_)))
+                        .map(|field| format!("authors.{}", field)),
+                ),
+                _ => {}
+            }
         }
+        unsupported
+    }
+
+    /// Author fields of `fields` this endpoint rejects, see [`Endpoint::unsupported_fields`].
+    pub fn unsupported_author_fields(&self, fields: &[AuthorField]) -> Vec<String> {
+        fields
+            .iter()
+            .flat_map(|field| match field {
+                AuthorField::Papers(nested) => unsupported_nested_fields("papers", nested),
+                _ => Vec::new(),
+            })
+            .collect()
     }
 }
 
-#[derive(Debug, Clone, Default, PartialEq)]
+impl PaperField {
+    /// Every scalar field of a paper: no embedding, TLDR, authors, citations or references.
+    pub fn all_basic() -> Vec<PaperField> {
+        vec![
+            PaperField::PaperId,
+            PaperField::Corpusid,
+            PaperField::ExternalIds,
+            PaperField::Url,
+            PaperField::Title,
+            PaperField::Abstract,
+            PaperField::Venue,
+            PaperField::PublicationVenue,
+            PaperField::Year,
+            PaperField::ReferenceCount,
+            PaperField::CitationCount,
+            PaperField::InfluentialCitationCount,
+            PaperField::IsOpenAccess,
+            PaperField::OpenAccessPdf,
+            PaperField::FieldsOfStudy,
+            PaperField::S2FieldsOfStudy,
+            PaperField::PublicationTypes,
+            PaperField::PublicationDate,
+            PaperField::Journal,
+            PaperField::CitationStyles,
+            PaperField::TextAvailability,
+        ]
+    }
+
+    /// Every field `endpoint` accepts, with [`PaperField::all_basic`] for nested papers and
+    /// [`AuthorField::all_basic`] for authors. Empty if the endpoint takes no `fields` parameter.
+    pub fn all_for(endpoint: &Endpoint) -> Vec<PaperField> {
+        if !endpoint.takes_fields() {
+            return Vec::new();
+        }
+        let mut fields = PaperField::all_basic();
+        fields.extend([
+            PaperField::Embedding,
+            PaperField::Tldr,
+            PaperField::Authors(AuthorField::all_basic()),
+            PaperField::Citations(PaperField::all_basic()),
+            PaperField::References(PaperField::all_basic()),
+            PaperField::Contexts,
+            PaperField::Intents,
+            PaperField::IsInfluential,
+            PaperField::ContextsWithIntent,
+        ]);
+        fields
+            .into_iter()
+            .filter(|field| {
+                endpoint
+                    .unsupported_fields(std::slice::from_ref(field))
+                    .is_empty()
+            })
+            .collect()
+    }
+
+    /// Parse a comma separated list of API field names, e.g. `title,authors.name,authors.hIndex`.
+    /// Consecutive nested fields are merged, so the list round-trips through [`Display`](fmt::Display).
+    pub fn parse_list(s: &str) -> Result<Vec<PaperField>, Error> {
+        let mut fields: Vec<PaperField> = Vec::new();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let field = PaperField::parse_one(name)?;
+            match (fields.last_mut(), field) {
+                (Some(PaperField::Authors(last)), PaperField::Authors(next)) => last.extend(next),
+                (Some(PaperField::Citations(last)), PaperField::Citations(next)) => {
+                    last.extend(next)
+                }
+                (Some(PaperField::References(last)), PaperField::References(next)) => {
+                    last.extend(next)
+                }
+                (_, field) => fields.push(field),
+            }
+        }
+        Ok(fields)
+    }
+
+    fn parse_one(name: &str) -> Result<PaperField, Error> {
+        let unknown = || Error::UnknownField(name.to_string());
+        if let Some((prefix, nested)) = name.split_once('.') {
+            return match prefix {
+                "authors" => Ok(PaperField::Authors(vec![nested.parse()?])),
+                "citations" => Ok(PaperField::Citations(vec![PaperField::parse_one(nested)?])),
+                "references" => Ok(PaperField::References(vec![PaperField::parse_one(nested)?])),
+                "embedding" if nested == "specter_v2" => Ok(PaperField::Embedding),
+                _ => Err(unknown()),
+            };
+        }
+        let field = match name {
+            "paperId" => PaperField::PaperId,
+            "corpusId" => PaperField::Corpusid,
+            "externalIds" => PaperField::ExternalIds,
+            "url" => PaperField::Url,
+            "title" => PaperField::Title,
+            "abstract" => PaperField::Abstract,
+            "venue" => PaperField::Venue,
+            "publicationVenue" => PaperField::PublicationVenue,
+            "year" => PaperField::Year,
+            "referenceCount" => PaperField::ReferenceCount,
+            "citationCount" => PaperField::CitationCount,
+            "influentialCitationCount" => PaperField::InfluentialCitationCount,
+            "isOpenAccess" => PaperField::IsOpenAccess,
+            "openAccessPdf" => PaperField::OpenAccessPdf,
+            "fieldsOfStudy" => PaperField::FieldsOfStudy,
+            "s2FieldsOfStudy" => PaperField::S2FieldsOfStudy,
+            "publicationTypes" => PaperField::PublicationTypes,
+            "publicationDate" => PaperField::PublicationDate,
+            "journal" => PaperField::Journal,
+            "citationStyles" => PaperField::CitationStyles,
+            "embedding" => PaperField::Embedding,
+            "contexts" => PaperField::Contexts,
+            "intents" => PaperField::Intents,
+            "isInfluential" => PaperField::IsInfluential,
+            "contextsWithIntent" => PaperField::ContextsWithIntent,
+            "tldr" => PaperField::Tldr,
+            "textAvailability" => PaperField::TextAvailability,
+            "authors" => PaperField::Authors(Vec::new()),
+            "citations" => PaperField::Citations(Vec::new()),
+            "references" => PaperField::References(Vec::new()),
+            _ => return Err(unknown()),
+        };
+        Ok(field)
+    }
+}
+
+/// A single field name (`title`, `authors.name`) or the comma separated names of one
+/// nested field (`citations.title,citations.year`).
+impl FromStr for PaperField {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = PaperField::parse_list(s)?;
+        match fields.len() {
+            1 => Ok(fields.remove(0)),
+            _ => Err(Error::UnknownField(s.to_string())),
+        }
+    }
+}
+
+impl AuthorField {
+    /// Every scalar field of an author, without their papers.
+    pub fn all_basic() -> Vec<AuthorField> {
+        vec![
+            AuthorField::AuthorId,
+            AuthorField::ExternalIds,
+            AuthorField::Url,
+            AuthorField::Name,
+            AuthorField::Aliases,
+            AuthorField::Affiliations,
+            AuthorField::Homepage,
+            AuthorField::PaperCount,
+            AuthorField::CitationCount,
+            AuthorField::HIndex,
+        ]
+    }
+
+    /// Every author field `endpoint` accepts, with [`PaperField::all_basic`] for their papers.
+    /// Empty if the endpoint takes no `fields` parameter.
+    pub fn all_for(endpoint: &Endpoint) -> Vec<AuthorField> {
+        if !endpoint.takes_fields() {
+            return Vec::new();
+        }
+        let mut fields = AuthorField::all_basic();
+        fields.push(AuthorField::Papers(PaperField::all_basic()));
+        fields
+            .into_iter()
+            .filter(|field| {
+                endpoint
+                    .unsupported_author_fields(std::slice::from_ref(field))
+                    .is_empty()
+            })
+            .collect()
+    }
+
+    /// Parse a comma separated list of API field names, e.g. `name,papers.title,papers.year`.
+    /// Consecutive `papers.*` fields are merged, so the list round-trips through [`Display`](fmt::Display).
+    pub fn parse_list(s: &str) -> Result<Vec<AuthorField>, Error> {
+        let mut fields: Vec<AuthorField> = Vec::new();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            match (fields.last_mut(), AuthorField::parse_one(name)?) {
+                (Some(AuthorField::Papers(last)), AuthorField::Papers(next)) => last.extend(next),
+                (_, field) => fields.push(field),
+            }
+        }
+        Ok(fields)
+    }
+
+    fn parse_one(name: &str) -> Result<AuthorField, Error> {
+        if let Some(nested) = name.strip_prefix("papers.") {
+            return Ok(AuthorField::Papers(vec![PaperField::parse_one(nested)?]));
+        }
+        let field = match name {
+            "authorId" => AuthorField::AuthorId,
+            "name" => AuthorField::Name,
+            "url" => AuthorField::Url,
+            "affiliations" => AuthorField::Affiliations,
+            "homepage" => AuthorField::Homepage,
+            "paperCount" => AuthorField::PaperCount,
+            "citationCount" => AuthorField::CitationCount,
+            "hIndex" => AuthorField::HIndex,
+            "externalIds" => AuthorField::ExternalIds,
+            "aliases" => AuthorField::Aliases,
+            "papers" => AuthorField::Papers(Vec::new()),
+            _ => return Err(Error::UnknownField(name.to_string())),
+        };
+        Ok(field)
+    }
+}
+
+/// A single field name (`name`, `papers.title`) or the comma separated names of one
+/// nested field (`papers.title,papers.year`).
+impl FromStr for AuthorField {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = AuthorField::parse_list(s)?;
+        match fields.len() {
+            1 => Ok(fields.remove(0)),
+            _ => Err(Error::UnknownField(s.to_string())),
+        }
+    }
+}
+
+/// Publication type of a paper.
+///
+/// Parsing ignores case, spaces and punctuation, so both the API values (`"ClinicalTrial"`,
+/// `"MetaAnalysis"`) and their spelled-out forms (`"Clinical Trial"`, `"Meta-Analysis"`) are
+/// recognized. Unknown values are kept as [`PublicationTypes::Other`]. Serialization writes
+/// [`PublicationTypes::api_name`], which is also what the search filters send.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub enum PublicationTypes {
     #[default]
     Review,
@@ -162,29 +789,50 @@ pub enum PublicationTypes {
     Study,
     Book,
     BookSection,
+    /// A value not known to this release.
+    Other(String),
 }
 
 impl PublicationTypes {
-    pub fn to_string(&self) -> String {
+    /// The value as the API returns it, e.g. `"MetaAnalysis"`.
+    pub fn api_name(&self) -> &str {
         match self {
-            PublicationTypes::Review => "Review".to_string(),
-            PublicationTypes::JournalArticle => "JournalArticle".to_string(),
-            PublicationTypes::CaseReport => "CaseReport".to_string(),
-            PublicationTypes::ClinicalTrial => "Clinical Trial".to_string(),
-            PublicationTypes::Conference => "Conference".to_string(),
-            PublicationTypes::Dataset => "Dataset".to_string(),
-            PublicationTypes::Editorial => "Editorial".to_string(),
-            PublicationTypes::LettersAndComments => "LettersAndComments".to_string(),
-            PublicationTypes::MetaAnalysis => "Meta-Analysis".to_string(),
-            PublicationTypes::News => "News".to_string(),
-            PublicationTypes::Study => "Study".to_string(),
-            PublicationTypes::Book => "Book".to_string(),
-            PublicationTypes::BookSection => "Book Section".to_string(),
+            PublicationTypes::Review => "Review",
+            PublicationTypes::JournalArticle => "JournalArticle",
+            PublicationTypes::CaseReport => "CaseReport",
+            PublicationTypes::ClinicalTrial => "ClinicalTrial",
+            PublicationTypes::Conference => "Conference",
+            PublicationTypes::Dataset => "Dataset",
+            PublicationTypes::Editorial => "Editorial",
+            PublicationTypes::LettersAndComments => "LettersAndComments",
+            PublicationTypes::MetaAnalysis => "MetaAnalysis",
+            PublicationTypes::News => "News",
+            PublicationTypes::Study => "Study",
+            PublicationTypes::Book => "Book",
+            PublicationTypes::BookSection => "BookSection",
+            PublicationTypes::Other(s) => s.as_str(),
         }
     }
 }
 
-#[derive(Debug, Clone, Default, PartialEq)]
+impl fmt::Display for PublicationTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            PublicationTypes::ClinicalTrial => "Clinical Trial",
+            PublicationTypes::MetaAnalysis => "Meta-Analysis",
+            PublicationTypes::BookSection => "Book Section",
+            other => other.api_name(),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Field of study of a paper.
+///
+/// Parsing ignores case, spaces and punctuation (`"computer science"`, `"ComputerScience"`).
+/// Unknown values are kept as [`FieldsOfStudy::Other`]. Serialization writes
+/// [`FieldsOfStudy::api_name`], which is also what the search filters send.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub enum FieldsOfStudy {
     #[default]
     ComputerScience,
@@ -210,37 +858,184 @@ pub enum FieldsOfStudy {
     Education,
     Law,
     Linguistics,
+    /// A value not known to this release.
+    Other(String),
 }
 
 impl FieldsOfStudy {
-    pub fn to_string(&self) -> String {
+    /// The value as the API returns it, e.g. `"Agricultural and Food Sciences"`.
+    pub fn api_name(&self) -> &str {
         match self {
-            FieldsOfStudy::ComputerScience => "Computer Science".to_string(),
-            FieldsOfStudy::Medicine => "Medicine".to_string(),
-            FieldsOfStudy::Chemistry => "Chemistry".to_string(),
-            FieldsOfStudy::Biology => "Biology".to_string(),
-            FieldsOfStudy::MaterialsScience => "Materials Science".to_string(),
-            FieldsOfStudy::Physics => "Physics".to_string(),
-            FieldsOfStudy::Geology => "Geology".to_string(),
-            FieldsOfStudy::Psychology => "Psychology".to_string(),
-            FieldsOfStudy::Art => "Art".to_string(),
-            FieldsOfStudy::Histroy => "Histroy".to_string(),
-            FieldsOfStudy::Geography => "Geography".to_string(),
-            FieldsOfStudy::Sociology => "Sociology".to_string(),
-            FieldsOfStudy::Business => "Business".to_string(),
-            FieldsOfStudy::PoliticalScience => "Political Science".to_string(),
-            FieldsOfStudy::Economics => "Economics".to_string(),
-            FieldsOfStudy::Philosophy => "Philosophy".to_string(),
-            FieldsOfStudy::Mathematics => "Mathematics".to_string(),
-            FieldsOfStudy::Engineering => "Engineering".to_string(),
-            FieldsOfStudy::EnvironmentalScience => "Environmental Science".to_string(),
-            FieldsOfStudy::AgriculturalAndFoodScience => {
-                "Agricultural and Food Science".to_string()
+            FieldsOfStudy::ComputerScience => "Computer Science",
+            FieldsOfStudy::Medicine => "Medicine",
+            FieldsOfStudy::Chemistry => "Chemistry",
+            FieldsOfStudy::Biology => "Biology",
+            FieldsOfStudy::MaterialsScience => "Materials Science",
+            FieldsOfStudy::Physics => "Physics",
+            FieldsOfStudy::Geology => "Geology",
+            FieldsOfStudy::Psychology => "Psychology",
+            FieldsOfStudy::Art => "Art",
+            FieldsOfStudy::Histroy => "History",
+            FieldsOfStudy::Geography => "Geography",
+            FieldsOfStudy::Sociology => "Sociology",
+            FieldsOfStudy::Business => "Business",
+            FieldsOfStudy::PoliticalScience => "Political Science",
+            FieldsOfStudy::Economics => "Economics",
+            FieldsOfStudy::Philosophy => "Philosophy",
+            FieldsOfStudy::Mathematics => "Mathematics",
+            FieldsOfStudy::Engineering => "Engineering",
+            FieldsOfStudy::EnvironmentalScience => "Environmental Science",
+            FieldsOfStudy::AgriculturalAndFoodScience => "Agricultural and Food Sciences",
+            FieldsOfStudy::Education => "Education",
+            FieldsOfStudy::Law => "Law",
+            FieldsOfStudy::Linguistics => "Linguistics",
+            FieldsOfStudy::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for FieldsOfStudy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            FieldsOfStudy::Histroy => "Histroy",
+            FieldsOfStudy::AgriculturalAndFoodScience => "Agricultural and Food Science",
+            other => other.api_name(),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Lowercase `s` and drop everything but letters and digits, e.g. `"Meta-Analysis"` -> `"metaanalysis"`.
+fn squash(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+impl From<&str> for PublicationTypes {
+    fn from(s: &str) -> Self {
+        match squash(s).as_str() {
+            "review" => PublicationTypes::Review,
+            "journalarticle" => PublicationTypes::JournalArticle,
+            "casereport" => PublicationTypes::CaseReport,
+            "clinicaltrial" => PublicationTypes::ClinicalTrial,
+            "conference" => PublicationTypes::Conference,
+            "dataset" => PublicationTypes::Dataset,
+            "editorial" => PublicationTypes::Editorial,
+            "lettersandcomments" => PublicationTypes::LettersAndComments,
+            "metaanalysis" => PublicationTypes::MetaAnalysis,
+            "news" => PublicationTypes::News,
+            "study" => PublicationTypes::Study,
+            "book" => PublicationTypes::Book,
+            "booksection" => PublicationTypes::BookSection,
+            _ => PublicationTypes::Other(s.to_string()),
+        }
+    }
+}
+
+impl FromStr for PublicationTypes {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(PublicationTypes::from(s))
+    }
+}
+
+impl From<&str> for FieldsOfStudy {
+    fn from(s: &str) -> Self {
+        match squash(s).as_str() {
+            "computerscience" => FieldsOfStudy::ComputerScience,
+            "medicine" => FieldsOfStudy::Medicine,
+            "chemistry" => FieldsOfStudy::Chemistry,
+            "biology" => FieldsOfStudy::Biology,
+            "materialsscience" => FieldsOfStudy::MaterialsScience,
+            "physics" => FieldsOfStudy::Physics,
+            "geology" => FieldsOfStudy::Geology,
+            "psychology" => FieldsOfStudy::Psychology,
+            "art" => FieldsOfStudy::Art,
+            "history" | "histroy" => FieldsOfStudy::Histroy,
+            "geography" => FieldsOfStudy::Geography,
+            "sociology" => FieldsOfStudy::Sociology,
+            "business" => FieldsOfStudy::Business,
+            "politicalscience" => FieldsOfStudy::PoliticalScience,
+            "economics" => FieldsOfStudy::Economics,
+            "philosophy" => FieldsOfStudy::Philosophy,
+            "mathematics" => FieldsOfStudy::Mathematics,
+            "engineering" => FieldsOfStudy::Engineering,
+            "environmentalscience" => FieldsOfStudy::EnvironmentalScience,
+            "agriculturalandfoodsciences" | "agriculturalandfoodscience" => {
+                FieldsOfStudy::AgriculturalAndFoodScience
+            }
+            "education" => FieldsOfStudy::Education,
+            "law" => FieldsOfStudy::Law,
+            "linguistics" => FieldsOfStudy::Linguistics,
+            _ => FieldsOfStudy::Other(s.to_string()),
+        }
+    }
+}
+
+impl FromStr for FieldsOfStudy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(FieldsOfStudy::from(s))
+    }
+}
+
+/// Serialize as the [`Display`](fmt::Display) string, or the string returned by `$name`, and
+/// deserialize through `FromStr`.
+macro_rules! serde_via_str {
+    (@deserialize $ty:ty) => {
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+    ($ty:ty) => {
+        impl Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
             }
-            FieldsOfStudy::Education => "Education".to_string(),
-            FieldsOfStudy::Law => "Law".to_string(),
-            FieldsOfStudy::Linguistics => "Linguistics".to_string(),
         }
+
+        serde_via_str!(@deserialize $ty);
+    };
+    ($ty:ty, $name:ident) => {
+        impl Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.$name())
+            }
+        }
+
+        serde_via_str!(@deserialize $ty);
+    };
+}
+
+serde_via_str!(PublicationTypes, api_name);
+serde_via_str!(FieldsOfStudy, api_name);
+serde_via_str!(PaperField);
+serde_via_str!(AuthorField);
+
+/// Candidate pool of single-seed recommendations.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum RecommendationPool {
+    /// Recent papers only.
+    #[default]
+    Recent,
+    /// All computer science papers.
+    AllCs,
+}
+
+impl fmt::Display for RecommendationPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            RecommendationPool::Recent => "recent",
+            RecommendationPool::AllCs => "all-cs",
+        };
+        write!(f, "{}", s)
     }
 }
 
@@ -256,6 +1051,17 @@ pub struct PublicationVenue {
     pub url: Option<String>,
     #[serde(default = "Option::default")]
     pub alternate_names: Option<Vec<String>>,
+    #[serde(default = "Option::default")]
+    pub issn: Option<String>,
+    #[serde(default = "Option::default")]
+    pub alternate_issns: Option<Vec<String>>,
+    #[serde(default = "Option::default")]
+    pub alternate_urls: Option<Vec<String>>,
+    #[serde(default = "Option::default")]
+    pub publisher: Option<String>,
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -274,8 +1080,13 @@ pub struct ExternalIds {
     pub mag: Option<String>,
     #[serde(rename = "ACL", default = "Option::default")]
     pub acl: Option<String>,
+    #[serde(rename = "MedLine", alias = "Medline", default = "Option::default")]
+    pub medline: Option<String>,
     #[serde(rename = "CorpusId", default = "Option::default")]
-    pub corpus_id: Option<u64>,
+    pub corpus_id: Option<CorpusId>,
+    /// Id types not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -284,6 +1095,25 @@ pub struct OpenAccessPdf {
     pub url: Option<String>,
     #[serde(default = "Option::default")]
     pub status: Option<String>,
+    #[serde(default = "Option::default")]
+    pub license: Option<String>,
+    #[serde(default = "Option::default")]
+    pub disclaimer: Option<String>,
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// External ids of an author. An author may have several DBLP names.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuthorExternalIds {
+    #[serde(rename = "DBLP", default = "Option::default")]
+    pub dblp: Option<Vec<String>>,
+    #[serde(rename = "ORCID", default = "Option::default")]
+    pub orcid: Option<String>,
+    /// Id types not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -312,7 +1142,7 @@ pub struct CitationStyles {
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Author {
-    #[serde(rename = "authorId", default = "Option::default")]
+    #[serde(rename = "authorId", alias = "authorid", default = "Option::default")]
     pub author_id: Option<String>,
     #[serde(default = "Option::default")]
     pub url: Option<String>,
@@ -322,12 +1152,33 @@ pub struct Author {
     pub affiliations: Option<Vec<String>>,
     #[serde(default = "Option::default")]
     pub homepage: Option<String>,
-    #[serde(rename = "paperCount", default = "Option::default")]
-    pub paper_count: Option<u32>,
-    #[serde(rename = "citationCount", default = "Option::default")]
-    pub citation_count: Option<u32>,
-    #[serde(rename = "hIndex", default = "Option::default")]
-    pub hindex: Option<u32>,
+    #[serde(
+        rename = "paperCount",
+        alias = "papercount",
+        default = "Option::default"
+    )]
+    pub paper_count: Option<u64>,
+    #[serde(
+        rename = "citationCount",
+        alias = "citationcount",
+        default = "Option::default"
+    )]
+    pub citation_count: Option<u64>,
+    #[serde(rename = "hIndex", alias = "hindex", default = "Option::default")]
+    pub hindex: Option<u64>,
+    #[serde(
+        rename = "externalIds",
+        alias = "externalids",
+        default = "Option::default"
+    )]
+    pub external_ids: Option<AuthorExternalIds>,
+    #[serde(default = "Option::default")]
+    pub aliases: Option<Vec<String>>,
+    #[serde(default = "Option::default")]
+    pub papers: Option<Vec<Paper>>,
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -338,12 +1189,21 @@ pub struct Embedding {
     pub vector: Vec<f64>,
 }
 
+/// Auto-generated one-sentence summary of a paper.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Tldr {
+    #[serde(default = "Option::default")]
+    pub model: Option<String>,
+    #[serde(default = "Option::default")]
+    pub text: Option<String>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Paper {
-    #[serde(rename = "paperId", default = "Option::default")]
+    #[serde(rename = "paperId", alias = "paperid", default = "Option::default")]
     pub paper_id: Option<String>,
-    #[serde(rename = "corpusId", default = "Option::default")]
-    pub corpus_id: Option<u32>,
+    #[serde(rename = "corpusId", alias = "corpusid", default = "Option::default")]
+    pub corpus_id: Option<CorpusId>,
     #[serde(default = "Option::default")]
     pub url: Option<String>,
     #[serde(default = "Option::default")]
@@ -356,29 +1216,73 @@ pub struct Paper {
     pub publication_venue: Option<PublicationVenue>,
     #[serde(default = "Option::default")]
     pub year: Option<u32>,
-    #[serde(rename = "referenceCount", default = "Option::default")]
-    pub reference_count: Option<u32>,
-    #[serde(rename = "citationCount", default = "Option::default")]
-    pub citation_count: Option<u32>,
-    #[serde(rename = "influentialCitationCount", default = "Option::default")]
-    pub influential_citation_count: Option<u32>,
-    #[serde(rename = "isOpenAccess", default = "Option::default")]
+    #[serde(
+        rename = "referenceCount",
+        alias = "referencecount",
+        default = "Option::default"
+    )]
+    pub reference_count: Option<u64>,
+    #[serde(
+        rename = "citationCount",
+        alias = "citationcount",
+        default = "Option::default"
+    )]
+    pub citation_count: Option<u64>,
+    #[serde(
+        rename = "influentialCitationCount",
+        alias = "influentialcitationcount",
+        default = "Option::default"
+    )]
+    pub influential_citation_count: Option<u64>,
+    #[serde(
+        rename = "isOpenAccess",
+        alias = "isopenaccess",
+        default = "Option::default"
+    )]
     pub is_open_access: Option<bool>,
-    #[serde(rename = "openAccessPdf", default = "Option::default")]
+    #[serde(
+        rename = "openAccessPdf",
+        alias = "openaccesspdf",
+        default = "Option::default"
+    )]
     pub open_access_pdf: Option<OpenAccessPdf>,
-    #[serde(rename = "externalIds", default = "Option::default")]
+    #[serde(
+        rename = "externalIds",
+        alias = "externalids",
+        default = "Option::default"
+    )]
     pub external_ids: Option<ExternalIds>,
-    #[serde(rename = "fieldsOfStudy", default = "Option::default")]
+    #[serde(
+        rename = "fieldsOfStudy",
+        alias = "fieldsofstudy",
+        default = "Option::default"
+    )]
     pub fields_of_study: Option<Vec<String>>,
-    #[serde(rename = "s2FieldsOfStudy", default = "Option::default")]
+    #[serde(
+        rename = "s2FieldsOfStudy",
+        alias = "s2fieldsofstudy",
+        default = "Option::default"
+    )]
     pub s2_fields_of_study: Option<Vec<S2FieldsOfStudy>>,
-    #[serde(rename = "publicationTypes", default = "Option::default")]
+    #[serde(
+        rename = "publicationTypes",
+        alias = "publicationtypes",
+        default = "Option::default"
+    )]
     pub publication_types: Option<Vec<String>>,
-    #[serde(rename = "publicationDate", default = "Option::default")]
+    #[serde(
+        rename = "publicationDate",
+        alias = "publicationdate",
+        default = "Option::default"
+    )]
     pub publication_date: Option<String>,
     #[serde(default = "Option::default")]
     pub journal: Option<Journal>,
-    #[serde(rename = "citationStyles", default = "Option::default")]
+    #[serde(
+        rename = "citationStyles",
+        alias = "citationstyles",
+        default = "Option::default"
+    )]
     pub citation_styles: Option<CitationStyles>,
     #[serde(default = "Option::default")]
     pub authors: Option<Vec<Author>>,
@@ -390,15 +1294,82 @@ pub struct Paper {
     pub embedding: Option<Embedding>,
     #[serde(rename = "matchScore", default = "Option::default")]
     pub match_score: Option<f64>,
+    #[serde(default = "Option::default")]
+    pub tldr: Option<Tldr>,
+    #[serde(
+        rename = "textAvailability",
+        alias = "textavailability",
+        default = "Option::default"
+    )]
+    pub text_availability: Option<String>,
+    #[serde(
+        rename = "isInfluential",
+        alias = "isinfluential",
+        default = "Option::default"
+    )]
+    pub is_influential: Option<bool>,
+    /// Fields not modeled above, e.g. ones added to the API after this release.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Paper {
+    /// `publication_types` parsed into [`PublicationTypes`], empty if not requested.
+    pub fn publication_types(&self) -> Vec<PublicationTypes> {
+        self.publication_types
+            .iter()
+            .flatten()
+            .map(|s| PublicationTypes::from(s.as_str()))
+            .collect()
+    }
+
+    /// `fields_of_study` parsed into [`FieldsOfStudy`], empty if not requested.
+    pub fn fields_of_study(&self) -> Vec<FieldsOfStudy> {
+        self.fields_of_study
+            .iter()
+            .flatten()
+            .map(|s| FieldsOfStudy::from(s.as_str()))
+            .collect()
+    }
+
+    pub fn has_publication_type(&self, publication_type: &PublicationTypes) -> bool {
+        self.publication_types().contains(publication_type)
+    }
+
+    pub fn has_field_of_study(&self, field_of_study: &FieldsOfStudy) -> bool {
+        self.fields_of_study().contains(field_of_study)
+    }
+
+    /// `publication_date` parsed into a [`PartialDate`], or `year` when the date is missing or malformed.
+    pub fn parsed_publication_date(&self) -> Option<PartialDate> {
+        self.publication_date
+            .as_deref()
+            .and_then(|date| date.parse().ok())
+            .or_else(|| {
+                self.year
+                    .and_then(|year| u16::try_from(year).ok())
+                    .map(PartialDate::year)
+            })
+    }
+
+    /// `publication_date` as a `chrono::NaiveDate`, `None` unless the full day is known.
+    #[cfg(feature = "chrono")]
+    pub fn publication_naive_date(&self) -> Option<chrono::NaiveDate> {
+        self.publication_date
+            .as_deref()
+            .and_then(|date| date.parse::<PartialDate>().ok())
+            .and_then(|date| date.to_naive_date())
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PaperIds {
-    #[serde(default = "usize::default")]
-    pub total: usize,
-    #[serde(default = "usize::default")]
-    pub offset: usize,
-    #[serde(default = "String::new")]
+    #[serde(default = "u64::default")]
+    pub total: u64,
+    #[serde(default = "u64::default")]
+    pub offset: u64,
+    /// Continuation token of `/paper/search/bulk`, empty once every result has been returned.
+    #[serde(default, deserialize_with = "null_to_default")]
     pub token: String,
     #[serde(default, deserialize_with = "null_to_default")]
     pub data: Vec<Paper>,
@@ -412,38 +1383,72 @@ pub struct PaperContext {
     pub intents: Option<Vec<String>>,
 }
 
+/// An edge of `/paper/{paper_id}/citations`: `citing_paper` cites the queried paper.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct ResponseData {
+pub struct Citation {
     #[serde(default = "Option::default")]
     pub contexts: Option<Vec<String>>,
     #[serde(default = "Option::default")]
     pub intents: Option<Vec<String>>,
     #[serde(rename = "contextsWithIntent", default = "Option::default")]
     pub contexts_with_intent: Option<Vec<PaperContext>>,
-    #[serde(default = "Option::default")]
-    pub isinfluential: Option<bool>,
+    #[serde(
+        rename = "isInfluential",
+        alias = "isinfluential",
+        default = "Option::default"
+    )]
+    pub is_influential: Option<bool>,
     #[serde(rename = "citingPaper", default = "Option::default")]
     pub citing_paper: Option<Paper>,
 }
 
+/// An edge of `/paper/{paper_id}/references`: the queried paper cites `cited_paper`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Reference {
+    #[serde(default = "Option::default")]
+    pub contexts: Option<Vec<String>>,
+    #[serde(default = "Option::default")]
+    pub intents: Option<Vec<String>>,
+    #[serde(rename = "contextsWithIntent", default = "Option::default")]
+    pub contexts_with_intent: Option<Vec<PaperContext>>,
+    #[serde(
+        rename = "isInfluential",
+        alias = "isinfluential",
+        default = "Option::default"
+    )]
+    pub is_influential: Option<bool>,
+    #[serde(rename = "citedPaper", default = "Option::default")]
+    pub cited_paper: Option<Paper>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CitationsResponse {
+    #[serde(default = "Option::default")]
+    pub offset: Option<u64>,
+    #[serde(default = "Option::default")]
+    pub next: Option<u64>,
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub data: Vec<Citation>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct ResponsePapers {
+pub struct ReferencesResponse {
     #[serde(default = "Option::default")]
     pub offset: Option<u64>,
     #[serde(default = "Option::default")]
     pub next: Option<u64>,
     #[serde(default, deserialize_with = "null_to_default")]
-    pub data: Vec<ResponseData>,
+    pub data: Vec<Reference>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AuthorSearchResponse {
-    #[serde(default = "usize::default")]
-    pub offset: usize,
+    #[serde(default = "u64::default")]
+    pub offset: u64,
     #[serde(default = "Option::default")]
-    pub next: Option<usize>,
-    #[serde(default = "usize::default")]
-    pub total: usize,
+    pub next: Option<u64>,
+    #[serde(default = "u64::default")]
+    pub total: u64,
     #[serde(default, deserialize_with = "null_to_default")]
     pub data: Vec<Author>,
 }
@@ -467,3 +1472,138 @@ pub struct PaperAuthorsResponse {
     #[serde(default, deserialize_with = "null_to_default")]
     pub data: Vec<Author>,
 }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutocompleteMatch {
+    #[serde(default = "String::new")]
+    pub id: String,
+    #[serde(default = "String::new")]
+    pub title: String,
+    #[serde(rename = "authorsYear", default = "Option::default")]
+    pub authors_year: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutocompleteResponse {
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub matches: Vec<AutocompleteMatch>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecommendationsResponse {
+    #[serde(
+        rename = "recommendedPapers",
+        default,
+        deserialize_with = "null_to_default"
+    )]
+    pub recommended_papers: Vec<Paper>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatasetSummary {
+    #[serde(default = "String::new")]
+    pub name: String,
+    #[serde(default = "Option::default")]
+    pub description: Option<String>,
+    #[serde(rename = "README", default = "Option::default")]
+    pub readme: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Release {
+    #[serde(default = "String::new")]
+    pub release_id: String,
+    #[serde(rename = "README", default = "Option::default")]
+    pub readme: Option<String>,
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub datasets: Vec<DatasetSummary>,
+}
+
+/// A dataset of a release. `files` are pre-signed download URLs that expire after a while.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Dataset {
+    #[serde(default = "String::new")]
+    pub name: String,
+    #[serde(default = "Option::default")]
+    pub description: Option<String>,
+    #[serde(rename = "README", default = "Option::default")]
+    pub readme: Option<String>,
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub files: Vec<String>,
+}
+
+/// Changes of a dataset between two consecutive releases.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatasetDiff {
+    #[serde(default = "String::new")]
+    pub from_release: String,
+    #[serde(default = "String::new")]
+    pub to_release: String,
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub update_files: Vec<String>,
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub delete_files: Vec<String>,
+}
+
+/// The chain of diffs bringing a dataset from `start_release` to `end_release`, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatasetDiffs {
+    #[serde(default = "String::new")]
+    pub dataset: String,
+    #[serde(default = "String::new")]
+    pub start_release: String,
+    #[serde(default = "String::new")]
+    pub end_release: String,
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub diffs: Vec<DatasetDiff>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnippetOffset {
+    #[serde(default = "Option::default")]
+    pub start: Option<u64>,
+    #[serde(default = "Option::default")]
+    pub end: Option<u64>,
+}
+
+/// A text passage of a paper. `snippet_offset` holds its character offsets in the paper's full text.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Snippet {
+    #[serde(default = "String::new")]
+    pub text: String,
+    /// `title`, `abstract` or `body`.
+    #[serde(rename = "snippetKind", default = "Option::default")]
+    pub snippet_kind: Option<String>,
+    #[serde(default = "Option::default")]
+    pub section: Option<String>,
+    #[serde(rename = "snippetOffset", default = "Option::default")]
+    pub snippet_offset: Option<SnippetOffset>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnippetPaper {
+    #[serde(rename = "corpusId", default = "Option::default")]
+    pub corpus_id: Option<CorpusId>,
+    #[serde(default = "Option::default")]
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub authors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnippetMatch {
+    #[serde(default)]
+    pub snippet: Snippet,
+    #[serde(default = "Option::default")]
+    pub score: Option<f64>,
+    #[serde(default)]
+    pub paper: SnippetPaper,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnippetSearchResponse {
+    #[serde(default, deserialize_with = "null_to_default")]
+    pub data: Vec<SnippetMatch>,
+    #[serde(rename = "retrievalVersion", default = "Option::default")]
+    pub retrieval_version: Option<String>,
+}
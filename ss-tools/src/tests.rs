@@ -1,5 +1,9 @@
+use crate::dates::{DateRange, PartialDate, YearRange};
 use crate::structs::*;
-use crate::QueryParams;
+use crate::{
+    pagination, Error, QueryParams, RateLimit, RateLimiter, RetryPolicy, SemanticScholar,
+    WaitEvent, WaitReporter,
+};
 
 // =============================================================================
 // Paper Deserialization Tests
@@ -239,16 +243,55 @@ fn test_paper_ids_deserialization() {
 }
 
 #[test]
-fn test_response_papers_deserialization() {
+fn test_citations_response_deserialization() {
     let json = r#"{
         "offset": 0,
         "next": 10,
-        "data": [{"citingPaper": {"paperId": "abc"}}]
+        "data": [{
+            "isInfluential": true,
+            "contexts": ["as shown in [1]"],
+            "intents": ["background"],
+            "contextsWithIntent": [{"context": "as shown in [1]", "intents": ["background"]}],
+            "citingPaper": {"paperId": "abc"}
+        }]
     }"#;
-    let response = serde_json::from_str::<ResponsePapers>(json).unwrap();
+    let response = serde_json::from_str::<CitationsResponse>(json).unwrap();
     assert_eq!(response.offset.unwrap(), 0);
     assert_eq!(response.next.unwrap(), 10);
     assert_eq!(response.data.len(), 1);
+    let citation = &response.data[0];
+    assert_eq!(citation.is_influential, Some(true));
+    assert_eq!(citation.intents.clone().unwrap(), vec!["background"]);
+    assert_eq!(citation.contexts_with_intent.clone().unwrap().len(), 1);
+    assert_eq!(
+        citation.citing_paper.clone().unwrap().paper_id.unwrap(),
+        "abc"
+    );
+}
+
+#[test]
+fn test_references_response_deserialization() {
+    let json = r#"{
+        "offset": 0,
+        "data": [
+            {"isInfluential": false, "contexts": [], "citedPaper": {"paperId": "def", "title": "Cited"}},
+            {"citedPaper": {"paperId": null}}
+        ]
+    }"#;
+    let response = serde_json::from_str::<ReferencesResponse>(json).unwrap();
+    assert!(response.next.is_none());
+    assert_eq!(response.data.len(), 2);
+    let reference = &response.data[0];
+    assert_eq!(reference.is_influential, Some(false));
+    let cited_paper = reference.cited_paper.clone().unwrap();
+    assert_eq!(cited_paper.paper_id.unwrap(), "def");
+    assert_eq!(cited_paper.title.unwrap(), "Cited");
+    assert!(response.data[1]
+        .cited_paper
+        .clone()
+        .unwrap()
+        .paper_id
+        .is_none());
 }
 
 #[test]
@@ -292,7 +335,10 @@ fn test_query_params_build_with_fields() {
 #[test]
 fn test_query_params_build_with_publication_types() {
     let mut params = QueryParams::default();
-    params.publication_types(vec![PublicationTypes::JournalArticle, PublicationTypes::Conference]);
+    params.publication_types(vec![
+        PublicationTypes::JournalArticle,
+        PublicationTypes::Conference,
+    ]);
     let result = params.build();
     assert!(result.contains("publicationTypes="));
     assert!(result.contains("JournalArticle"));
@@ -310,7 +356,7 @@ fn test_query_params_build_with_min_citation_count() {
 #[test]
 fn test_query_params_build_with_year() {
     let mut params = QueryParams::default();
-    params.year("2020-2023");
+    params.year(YearRange::between(2020, 2023));
     let result = params.build();
     assert!(result.contains("year=2020-2023"));
 }
@@ -318,7 +364,7 @@ fn test_query_params_build_with_year() {
 #[test]
 fn test_query_params_build_with_publication_date() {
     let mut params = QueryParams::default();
-    params.publication_date_or_year("2020-01-01:2023-12-31");
+    params.publication_date_or_year("2020-01-01:2023-12-31".parse().unwrap());
     let result = params.build();
     assert!(result.contains("publicationDateOrYear=2020-01-01:2023-12-31"));
 }
@@ -334,7 +380,10 @@ fn test_query_params_build_with_venue() {
 #[test]
 fn test_query_params_build_with_fields_of_study() {
     let mut params = QueryParams::default();
-    params.fields_of_study(vec![FieldsOfStudy::ComputerScience, FieldsOfStudy::Mathematics]);
+    params.fields_of_study(vec![
+        FieldsOfStudy::ComputerScience,
+        FieldsOfStudy::Mathematics,
+    ]);
     let result = params.build();
     assert!(result.contains("fieldsOfStudy="));
     assert!(result.contains("Computer%20Science"));
@@ -355,7 +404,12 @@ fn test_query_params_build_with_token() {
     let mut params = QueryParams::default();
     params.token("next_page_token");
     let result = params.build();
-    assert!(result.contains("token=next_page_token"));
+    assert!(result.contains("token=next%5Fpage%5Ftoken"));
+
+    // Base64 tokens carry characters that are reserved in a query string
+    params.token("PCOA3RZZB2ADADAEALPD+/a2w==");
+    let result = params.build();
+    assert!(result.contains("token=PCOA3RZZB2ADADAEALPD%2B%2Fa2w%3D%3D"));
 }
 
 #[test]
@@ -387,7 +441,7 @@ fn test_query_params_build_multiple() {
     let mut params = QueryParams::default();
     params.query_text("machine learning");
     params.min_citation_count(10);
-    params.year("2020");
+    params.year(YearRange::year(2020));
     let result = params.build();
     assert!(result.starts_with("?"));
     assert!(result.contains("query="));
@@ -412,7 +466,10 @@ fn test_paper_field_to_string_basic() {
     assert_eq!(PaperField::Year.to_string(), "year");
     assert_eq!(PaperField::ReferenceCount.to_string(), "referenceCount");
     assert_eq!(PaperField::CitationCount.to_string(), "citationCount");
-    assert_eq!(PaperField::InfluentialCitationCount.to_string(), "influentialCitationCount");
+    assert_eq!(
+        PaperField::InfluentialCitationCount.to_string(),
+        "influentialCitationCount"
+    );
     assert_eq!(PaperField::IsOpenAccess.to_string(), "isOpenAccess");
     assert_eq!(PaperField::OpenAccessPdf.to_string(), "openAccessPdf");
     assert_eq!(PaperField::FieldsOfStudy.to_string(), "fieldsOfStudy");
@@ -425,8 +482,12 @@ fn test_paper_field_to_string_basic() {
     assert_eq!(PaperField::Contexts.to_string(), "contexts");
     assert_eq!(PaperField::Intents.to_string(), "intents");
     assert_eq!(PaperField::IsInfluential.to_string(), "isInfluential");
-    assert_eq!(PaperField::ContextsWithIntent.to_string(), "contextsWithIntent");
+    assert_eq!(
+        PaperField::ContextsWithIntent.to_string(),
+        "contextsWithIntent"
+    );
     assert_eq!(PaperField::ExternalIds.to_string(), "externalIds");
+    assert_eq!(PaperField::Tldr.to_string(), "tldr");
 }
 
 #[test]
@@ -476,13 +537,22 @@ fn test_author_field_to_string() {
 #[test]
 fn test_publication_types_to_string() {
     assert_eq!(PublicationTypes::Review.to_string(), "Review");
-    assert_eq!(PublicationTypes::JournalArticle.to_string(), "JournalArticle");
+    assert_eq!(
+        PublicationTypes::JournalArticle.to_string(),
+        "JournalArticle"
+    );
     assert_eq!(PublicationTypes::CaseReport.to_string(), "CaseReport");
-    assert_eq!(PublicationTypes::ClinicalTrial.to_string(), "Clinical Trial");
+    assert_eq!(
+        PublicationTypes::ClinicalTrial.to_string(),
+        "Clinical Trial"
+    );
     assert_eq!(PublicationTypes::Conference.to_string(), "Conference");
     assert_eq!(PublicationTypes::Dataset.to_string(), "Dataset");
     assert_eq!(PublicationTypes::Editorial.to_string(), "Editorial");
-    assert_eq!(PublicationTypes::LettersAndComments.to_string(), "LettersAndComments");
+    assert_eq!(
+        PublicationTypes::LettersAndComments.to_string(),
+        "LettersAndComments"
+    );
     assert_eq!(PublicationTypes::MetaAnalysis.to_string(), "Meta-Analysis");
     assert_eq!(PublicationTypes::News.to_string(), "News");
     assert_eq!(PublicationTypes::Study.to_string(), "Study");
@@ -496,11 +566,17 @@ fn test_publication_types_to_string() {
 
 #[test]
 fn test_fields_of_study_to_string() {
-    assert_eq!(FieldsOfStudy::ComputerScience.to_string(), "Computer Science");
+    assert_eq!(
+        FieldsOfStudy::ComputerScience.to_string(),
+        "Computer Science"
+    );
     assert_eq!(FieldsOfStudy::Medicine.to_string(), "Medicine");
     assert_eq!(FieldsOfStudy::Chemistry.to_string(), "Chemistry");
     assert_eq!(FieldsOfStudy::Biology.to_string(), "Biology");
-    assert_eq!(FieldsOfStudy::MaterialsScience.to_string(), "Materials Science");
+    assert_eq!(
+        FieldsOfStudy::MaterialsScience.to_string(),
+        "Materials Science"
+    );
     assert_eq!(FieldsOfStudy::Physics.to_string(), "Physics");
     assert_eq!(FieldsOfStudy::Geology.to_string(), "Geology");
     assert_eq!(FieldsOfStudy::Psychology.to_string(), "Psychology");
@@ -509,13 +585,22 @@ fn test_fields_of_study_to_string() {
     assert_eq!(FieldsOfStudy::Geography.to_string(), "Geography");
     assert_eq!(FieldsOfStudy::Sociology.to_string(), "Sociology");
     assert_eq!(FieldsOfStudy::Business.to_string(), "Business");
-    assert_eq!(FieldsOfStudy::PoliticalScience.to_string(), "Political Science");
+    assert_eq!(
+        FieldsOfStudy::PoliticalScience.to_string(),
+        "Political Science"
+    );
     assert_eq!(FieldsOfStudy::Economics.to_string(), "Economics");
     assert_eq!(FieldsOfStudy::Philosophy.to_string(), "Philosophy");
     assert_eq!(FieldsOfStudy::Mathematics.to_string(), "Mathematics");
     assert_eq!(FieldsOfStudy::Engineering.to_string(), "Engineering");
-    assert_eq!(FieldsOfStudy::EnvironmentalScience.to_string(), "Environmental Science");
-    assert_eq!(FieldsOfStudy::AgriculturalAndFoodScience.to_string(), "Agricultural and Food Science");
+    assert_eq!(
+        FieldsOfStudy::EnvironmentalScience.to_string(),
+        "Environmental Science"
+    );
+    assert_eq!(
+        FieldsOfStudy::AgriculturalAndFoodScience.to_string(),
+        "Agricultural and Food Science"
+    );
     assert_eq!(FieldsOfStudy::Education.to_string(), "Education");
     assert_eq!(FieldsOfStudy::Law.to_string(), "Law");
     assert_eq!(FieldsOfStudy::Linguistics.to_string(), "Linguistics");
@@ -532,12 +617,12 @@ fn test_query_params_builder_chain() {
         .paper_id("abc123")
         .query_text("test")
         .min_citation_count(5)
-        .year("2020");
+        .year(YearRange::year(2020));
 
     assert_eq!(params.paper_id, "abc123");
     assert_eq!(params.query_text.unwrap(), "test");
     assert_eq!(params.min_citation_count.unwrap(), 5);
-    assert_eq!(params.year.unwrap(), "2020");
+    assert_eq!(params.year.unwrap().to_string(), "2020");
 }
 
 // =============================================================================
@@ -654,3 +739,2623 @@ fn test_query_params_author_fields_builder() {
     assert!(fields.contains(&AuthorField::Name));
     assert!(fields.contains(&AuthorField::HIndex));
 }
+
+// =============================================================================
+// SemanticScholarBuilder Tests
+// =============================================================================
+
+#[test]
+fn test_builder_defaults() {
+    let ss = SemanticScholar::builder().api_key("").build().unwrap();
+    assert_eq!(ss.base_url(), "https://api.semanticscholar.org/graph/v1");
+    assert_eq!(
+        ss.recommendations_base_url(),
+        "https://api.semanticscholar.org/recommendations/v1"
+    );
+    assert_eq!(
+        ss.datasets_base_url(),
+        "https://api.semanticscholar.org/datasets/v1"
+    );
+    assert!(ss.api_key.is_empty());
+}
+
+#[test]
+fn test_builder_custom_settings() {
+    let ss = SemanticScholar::builder()
+        .base_url("http://localhost:8080/graph/v1/")
+        .recommendations_base_url("http://localhost:8080/recommendations/v1")
+        .datasets_base_url("http://localhost:8080/datasets/v1")
+        .api_key("test-key")
+        .timeout(std::time::Duration::from_secs(5))
+        .user_agent("ss-tools-test")
+        .proxy("http://127.0.0.1:3128")
+        .build()
+        .unwrap();
+    assert_eq!(ss.base_url(), "http://localhost:8080/graph/v1");
+    assert_eq!(
+        ss.recommendations_base_url(),
+        "http://localhost:8080/recommendations/v1"
+    );
+    assert_eq!(ss.datasets_base_url(), "http://localhost:8080/datasets/v1");
+    assert_eq!(ss.api_key, "test-key");
+}
+
+#[test]
+fn test_builder_invalid_proxy() {
+    let result = SemanticScholar::builder().proxy("not a url").build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_get_url_uses_base_url() {
+    let ss = SemanticScholar::builder()
+        .base_url("http://localhost:8080/graph/v1")
+        .build()
+        .unwrap();
+    let mut params = QueryParams::default();
+    params.paper_id("abc123");
+    assert_eq!(
+        ss.get_url(Endpoint::GetPaperDetails, &mut params).unwrap(),
+        "http://localhost:8080/graph/v1/paper/abc123"
+    );
+    assert_eq!(
+        ss.get_url(Endpoint::GetCitationsOfAPaper, &mut params)
+            .unwrap(),
+        "http://localhost:8080/graph/v1/paper/abc123/citations"
+    );
+}
+
+#[test]
+fn test_builder_with_injected_client() {
+    let client = reqwest::Client::builder()
+        .user_agent("injected")
+        .build()
+        .unwrap();
+    // Client settings on the builder are ignored when a client is injected
+    let ss = SemanticScholar::builder()
+        .client(client)
+        .proxy("not a url")
+        .api_key("test-key")
+        .build()
+        .unwrap();
+    assert_eq!(ss.api_key, "test-key");
+    let cloned = ss.clone();
+    assert_eq!(cloned.base_url(), ss.base_url());
+}
+
+#[tokio::test]
+async fn test_default_user_agent_has_crate_version() {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/paper/abc"))
+        .and(header(
+            "user-agent",
+            format!("ss-tools/{}", env!("CARGO_PKG_VERSION")).as_str(),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"paperId": "abc"})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("abc");
+    ss.query_paper_details(query_params).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_injected_client_headers_are_sent() {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/paper/abc"))
+        .and(header("user-agent", "injected"))
+        .and(header("x-trace-id", "42"))
+        .and(header("x-api-key", "test-key"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"paperId": "abc"})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-trace-id", "42".parse().unwrap());
+    let client = reqwest::Client::builder()
+        .user_agent("injected")
+        .default_headers(headers)
+        .build()
+        .unwrap();
+    let mut ss = SemanticScholar::builder()
+        .client(client)
+        .user_agent("ignored")
+        .api_key("test-key")
+        .base_url(&server.uri())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("abc");
+    ss.query_paper_details(query_params).await.unwrap();
+}
+
+// =============================================================================
+// Error Tests
+// =============================================================================
+
+#[test]
+fn test_error_from_status_not_found() {
+    let url = "https://api.semanticscholar.org/graph/v1/paper/xxx";
+    let body = r#"{"error": "Paper with id xxx not found"}"#;
+    let error = Error::from_status(404, Endpoint::GetPaperDetails, url, body, None);
+    assert!(matches!(error, Error::NotFound(_)));
+    assert_eq!(error.status(), Some(404));
+    assert_eq!(error.endpoint(), Some(&Endpoint::GetPaperDetails));
+    assert_eq!(error.url(), Some(url));
+    let api_error = error.api_error().unwrap();
+    assert_eq!(
+        api_error.error.as_deref(),
+        Some("Paper with id xxx not found")
+    );
+    assert!(api_error.message.is_none());
+    assert!(error.to_string().contains("Paper with id xxx not found"));
+}
+
+#[test]
+fn test_error_from_status_variants() {
+    let url = "https://api.semanticscholar.org/graph/v1/paper/search";
+    let endpoint = Endpoint::GetPapersByTitle;
+    assert!(matches!(
+        Error::from_status(
+            400,
+            endpoint.clone(),
+            url,
+            r#"{"error": "Unrecognized or unsupported fields: [foo]"}"#,
+            None
+        ),
+        Error::BadRequest(_)
+    ));
+    assert!(matches!(
+        Error::from_status(401, endpoint.clone(), url, "", None),
+        Error::Unauthorized(_)
+    ));
+    assert!(matches!(
+        Error::from_status(
+            403,
+            endpoint.clone(),
+            url,
+            r#"{"message": "Forbidden"}"#,
+            None
+        ),
+        Error::Unauthorized(_)
+    ));
+    assert!(matches!(
+        Error::from_status(
+            429,
+            endpoint.clone(),
+            url,
+            r#"{"message": "Too Many Requests"}"#,
+            None
+        ),
+        Error::RateLimited(_)
+    ));
+    assert!(matches!(
+        Error::from_status(
+            503,
+            endpoint.clone(),
+            url,
+            "<html>Service Unavailable</html>",
+            None
+        ),
+        Error::Server(_)
+    ));
+    assert!(matches!(
+        Error::from_status(418, endpoint.clone(), url, "", None),
+        Error::Status(_)
+    ));
+}
+
+#[test]
+fn test_error_from_status_message_body() {
+    let error = Error::from_status(
+        429,
+        Endpoint::GetMultiplePpaerDetails,
+        "https://api.semanticscholar.org/graph/v1/paper/batch",
+        r#"{"message": "Too Many Requests. Please wait and try again or apply for a key for higher rate limits.", "code": "429"}"#,
+        None,
+    );
+    let api_error = error.api_error().unwrap();
+    assert!(api_error.error.is_none());
+    assert!(api_error
+        .message
+        .as_deref()
+        .unwrap()
+        .starts_with("Too Many Requests"));
+}
+
+#[test]
+fn test_error_without_response() {
+    let error = Error::EmptyResponse {
+        endpoint: Endpoint::GetAPaperByTitle,
+        url: "https://api.semanticscholar.org/graph/v1/paper/search/match".to_string(),
+    };
+    assert_eq!(error.status(), None);
+    assert_eq!(error.endpoint(), Some(&Endpoint::GetAPaperByTitle));
+    assert!(error.api_error().is_none());
+
+    let error = Error::Config("invalid proxy".to_string());
+    assert_eq!(error.endpoint(), None);
+    assert_eq!(error.url(), None);
+}
+
+#[tokio::test]
+async fn test_public_methods_return_typed_errors() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/paper/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(serde_json::json!({"error": "Paper with id missing not found"})),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/author/1741101"))
+        .respond_with(
+            ResponseTemplate::new(401)
+                .set_body_json(serde_json::json!({"message": "Unauthorized"})),
+        )
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("missing");
+    let error = ss.query_paper_details(query_params).await.unwrap_err();
+    let Error::NotFound(api_error) = &error else {
+        panic!("expected NotFound, got {:?}", error);
+    };
+    assert_eq!(api_error.status, 404);
+    assert_eq!(api_error.endpoint, Endpoint::GetPaperDetails);
+    assert!(api_error
+        .url
+        .starts_with(&format!("{}/paper/missing", server.uri())));
+    assert_eq!(
+        api_error.error.as_deref(),
+        Some("Paper with id missing not found")
+    );
+
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("1741101");
+    let error = ss.query_author_details(query_params).await.unwrap_err();
+    let Error::Unauthorized(api_error) = &error else {
+        panic!("expected Unauthorized, got {:?}", error);
+    };
+    assert_eq!(api_error.status, 401);
+    assert_eq!(api_error.message.as_deref(), Some("Unauthorized"));
+    assert!(error.to_string().contains("Unauthorized"));
+}
+
+// =============================================================================
+// RetryPolicy Tests
+// =============================================================================
+
+fn api_error(status: u16, retry_after: Option<std::time::Duration>) -> Error {
+    Error::from_status(
+        status,
+        Endpoint::GetPaperDetails,
+        "https://api.semanticscholar.org/graph/v1/paper/xxx",
+        "",
+        retry_after,
+    )
+}
+
+#[test]
+fn test_retry_policy_retries_only_transient_errors() {
+    let policy = RetryPolicy::default();
+    assert!(policy.should_retry(1, &api_error(429, None)));
+    assert!(policy.should_retry(1, &api_error(500, None)));
+    assert!(policy.should_retry(1, &api_error(503, None)));
+    assert!(policy.should_retry(
+        1,
+        &Error::Timeout {
+            endpoint: Endpoint::GetPaperDetails,
+            url: "".to_string(),
+        }
+    ));
+    assert!(!policy.should_retry(1, &api_error(400, None)));
+    assert!(!policy.should_retry(1, &api_error(403, None)));
+    assert!(!policy.should_retry(1, &api_error(404, None)));
+    assert!(!policy.should_retry(
+        1,
+        &Error::EmptyResponse {
+            endpoint: Endpoint::GetPapersByTitle,
+            url: "".to_string(),
+        }
+    ));
+}
+
+#[test]
+fn test_retry_policy_max_attempts() {
+    let mut policy = RetryPolicy::default();
+    policy.max_attempts(3);
+    let error = api_error(429, None);
+    assert!(policy.should_retry(1, &error));
+    assert!(policy.should_retry(2, &error));
+    assert!(!policy.should_retry(3, &error));
+
+    let policy = RetryPolicy::none();
+    assert!(!policy.should_retry(1, &error));
+}
+
+#[test]
+fn test_retry_policy_exponential_backoff() {
+    let mut policy = RetryPolicy::default();
+    policy
+        .initial_backoff(std::time::Duration::from_secs(1))
+        .max_backoff(std::time::Duration::from_secs(10))
+        .multiplier(2.0);
+    assert_eq!(policy.backoff(1), std::time::Duration::from_secs(1));
+    assert_eq!(policy.backoff(2), std::time::Duration::from_secs(2));
+    assert_eq!(policy.backoff(3), std::time::Duration::from_secs(4));
+    assert_eq!(policy.backoff(4), std::time::Duration::from_secs(8));
+    assert_eq!(policy.backoff(5), std::time::Duration::from_secs(10));
+    assert_eq!(policy.backoff(100), std::time::Duration::from_secs(10));
+}
+
+#[test]
+fn test_retry_policy_jitter_bounds() {
+    let mut policy = RetryPolicy::default();
+    policy
+        .initial_backoff(std::time::Duration::from_secs(10))
+        .jitter(0.5);
+    let error = api_error(503, None);
+    for _ in 0..100 {
+        let delay = policy.delay(1, &error);
+        assert!(delay >= std::time::Duration::from_secs(5));
+        assert!(delay <= std::time::Duration::from_secs(15));
+    }
+
+    policy.jitter(0.0);
+    assert_eq!(policy.delay(1, &error), std::time::Duration::from_secs(10));
+}
+
+#[test]
+fn test_retry_policy_respects_retry_after() {
+    let mut policy = RetryPolicy::default();
+    let error = api_error(429, Some(std::time::Duration::from_secs(42)));
+    assert_eq!(policy.delay(1, &error), std::time::Duration::from_secs(42));
+    assert_eq!(
+        error.api_error().unwrap().retry_after,
+        Some(std::time::Duration::from_secs(42))
+    );
+
+    policy.respect_retry_after(false).jitter(0.0);
+    assert_eq!(policy.delay(1, &error), std::time::Duration::from_secs(1));
+}
+
+#[test]
+fn test_retry_policy_custom_predicate() {
+    let mut policy = RetryPolicy::default();
+    policy.retry_on(|error| matches!(error, Error::NotFound(_)));
+    assert!(policy.should_retry(1, &api_error(404, None)));
+    assert!(!policy.should_retry(1, &api_error(429, None)));
+}
+
+#[test]
+fn test_retry_policy_caps_retry_after() {
+    let mut policy = RetryPolicy::default();
+    policy.max_backoff(std::time::Duration::from_secs(10));
+    let error = api_error(429, Some(std::time::Duration::from_secs(3600)));
+    assert_eq!(policy.delay(1, &error), std::time::Duration::from_secs(10));
+}
+
+#[test]
+fn test_parse_retry_after() {
+    use crate::retry::parse_retry_after;
+
+    assert_eq!(
+        parse_retry_after(" 120 "),
+        Some(std::time::Duration::from_secs(120))
+    );
+    assert_eq!(
+        parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+        Some(std::time::Duration::ZERO)
+    );
+    let in_an_hour = std::time::SystemTime::now() + std::time::Duration::from_secs(3600);
+    let delay = parse_retry_after(&httpdate::fmt_http_date(in_an_hour)).unwrap();
+    assert!(delay > std::time::Duration::from_secs(3590));
+    assert!(delay <= std::time::Duration::from_secs(3600));
+    assert_eq!(parse_retry_after("soon"), None);
+}
+
+/// Client retrying up to 3 times with 1ms backoffs (capped at 50ms), recording each wait.
+fn retrying_client(
+    server: &wiremock::MockServer,
+) -> (
+    SemanticScholar,
+    std::sync::Arc<std::sync::Mutex<Vec<WaitEvent>>>,
+) {
+    let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let reporter = {
+        let events = events.clone();
+        WaitReporter::callback(move |event| events.lock().unwrap().push(event.clone()))
+    };
+    let mut policy = RetryPolicy::default();
+    policy
+        .max_attempts(3)
+        .initial_backoff(std::time::Duration::from_millis(1))
+        .max_backoff(std::time::Duration::from_millis(50))
+        .jitter(0.0);
+    let ss = SemanticScholar::builder()
+        .api_key("")
+        .base_url(&server.uri())
+        .retry_policy(policy)
+        .wait_reporter(reporter)
+        .build()
+        .unwrap();
+    (ss, events)
+}
+
+fn paper_details_params() -> QueryParams {
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("abc");
+    query_params
+}
+
+#[tokio::test]
+async fn test_transient_errors_are_retried() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    for status in [429, 503] {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper/abc"))
+            .respond_with(ResponseTemplate::new(status))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/paper/abc"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"paperId": "abc"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let (mut ss, events) = retrying_client(&server);
+        let paper = ss
+            .query_paper_details(paper_details_params())
+            .await
+            .unwrap();
+        assert_eq!(paper.paper_id.as_deref(), Some("abc"));
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1, "{}", status);
+        assert_eq!(events[0].delay, std::time::Duration::from_millis(1));
+    }
+}
+
+#[tokio::test]
+async fn test_client_errors_are_not_retried() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    for status in [400, 404] {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper/abc"))
+            .respond_with(ResponseTemplate::new(status))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let (mut ss, events) = retrying_client(&server);
+        let error = ss
+            .query_paper_details(paper_details_params())
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(status));
+        assert!(events.lock().unwrap().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn test_retry_after_header_is_used() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let in_an_hour = std::time::SystemTime::now() + std::time::Duration::from_secs(3600);
+    for retry_after in ["0".to_string(), httpdate::fmt_http_date(in_an_hour)] {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper/abc"))
+            .respond_with(
+                ResponseTemplate::new(429).insert_header("Retry-After", retry_after.as_str()),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/paper/abc"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"paperId": "abc"})),
+            )
+            .mount(&server)
+            .await;
+
+        let (mut ss, events) = retrying_client(&server);
+        ss.query_paper_details(paper_details_params())
+            .await
+            .unwrap();
+        let delays = events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|event| event.delay)
+            .collect::<Vec<_>>();
+        // "0" replaces the 1ms backoff, the date an hour ahead is capped at max_backoff
+        let expected = if retry_after == "0" {
+            std::time::Duration::ZERO
+        } else {
+            std::time::Duration::from_millis(50)
+        };
+        assert_eq!(delays, vec![expected]);
+    }
+}
+
+// =============================================================================
+// RateLimiter Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_rate_limiter_allows_burst_up_to_capacity() {
+    let limiter = RateLimiter::new(RateLimit::new(3, std::time::Duration::from_secs(10)));
+    let start = std::time::Instant::now();
+    for _ in 0..3 {
+        limiter.acquire().await;
+    }
+    assert!(start.elapsed() < std::time::Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn test_rate_limiter_throttles_beyond_capacity() {
+    let limiter = RateLimiter::new(RateLimit::new(2, std::time::Duration::from_millis(200)));
+    let start = std::time::Instant::now();
+    for _ in 0..4 {
+        limiter.acquire().await;
+    }
+    // 2 tokens up front, then one token every 100ms
+    assert!(start.elapsed() >= std::time::Duration::from_millis(190));
+}
+
+#[tokio::test]
+async fn test_rate_limiter_is_shared_between_clones() {
+    let limiter = RateLimiter::new(RateLimit::new(1, std::time::Duration::from_millis(100)));
+    let cloned = limiter.clone();
+    let start = std::time::Instant::now();
+    let (_, _) = tokio::join!(limiter.acquire(), cloned.acquire());
+    assert!(start.elapsed() >= std::time::Duration::from_millis(90));
+}
+
+#[test]
+fn test_rate_limit_constructors() {
+    assert_eq!(
+        RateLimit::per_second(10),
+        RateLimit::new(10, std::time::Duration::from_secs(1))
+    );
+    assert_eq!(
+        RateLimit::per_minute(100),
+        RateLimit::new(100, std::time::Duration::from_secs(60))
+    );
+    assert_eq!(
+        RateLimit::new(0, std::time::Duration::from_secs(1)).requests,
+        1
+    );
+}
+
+#[test]
+fn test_builder_rate_limits() {
+    // An API key gets the 1 request per second default
+    let ss = SemanticScholar::builder().api_key("key").build().unwrap();
+    assert_eq!(
+        ss.rate_limiter(&Endpoint::GetPaperDetails).unwrap().limit(),
+        RateLimit::per_second(1)
+    );
+
+    // No API key, no default budget
+    let ss = SemanticScholar::builder().api_key("").build().unwrap();
+    assert!(ss.rate_limiter(&Endpoint::GetPaperDetails).is_none());
+
+    // Per-endpoint budgets override the default one
+    let ss = SemanticScholar::builder()
+        .api_key("key")
+        .rate_limit(Some(RateLimit::per_second(10)))
+        .endpoint_rate_limit(Endpoint::GetMultiplePpaerDetails, RateLimit::per_second(1))
+        .build()
+        .unwrap();
+    assert_eq!(
+        ss.rate_limiter(&Endpoint::GetPaperDetails).unwrap().limit(),
+        RateLimit::per_second(10)
+    );
+    assert_eq!(
+        ss.rate_limiter(&Endpoint::GetMultiplePpaerDetails)
+            .unwrap()
+            .limit(),
+        RateLimit::per_second(1)
+    );
+
+    // The default budget can be switched off
+    let ss = SemanticScholar::builder()
+        .api_key("key")
+        .rate_limit(None)
+        .build()
+        .unwrap();
+    assert!(ss.rate_limiter(&Endpoint::GetPaperDetails).is_none());
+}
+
+// =============================================================================
+// WaitReporter Tests
+// =============================================================================
+
+fn wait_event(delay: std::time::Duration) -> WaitEvent {
+    WaitEvent {
+        endpoint: Endpoint::GetPaperDetails,
+        url: "https://api.semanticscholar.org/graph/v1/paper/xxx".to_string(),
+        attempt: 2,
+        delay,
+        cause: "rate limited".to_string(),
+    }
+}
+
+#[test]
+fn test_wait_reporter_default_is_tracing() {
+    assert!(matches!(WaitReporter::default(), WaitReporter::Tracing));
+    assert_eq!(
+        format!("{:?}", WaitReporter::callback(|_| {})),
+        "Callback(..)"
+    );
+}
+
+#[tokio::test]
+async fn test_wait_reporter_callback_receives_event() {
+    let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let reporter = {
+        let events = events.clone();
+        WaitReporter::callback(move |event| events.lock().unwrap().push(event.clone()))
+    };
+    let delay = std::time::Duration::from_millis(50);
+    let start = std::time::Instant::now();
+    reporter.wait(wait_event(delay)).await;
+
+    assert!(start.elapsed() >= delay);
+    assert_eq!(*events.lock().unwrap(), vec![wait_event(delay)]);
+}
+
+#[tokio::test]
+async fn test_wait_reporter_silent_and_tracing_sleep() {
+    let delay = std::time::Duration::from_millis(20);
+    for reporter in [WaitReporter::Silent, WaitReporter::Tracing] {
+        let start = std::time::Instant::now();
+        reporter.wait(wait_event(delay)).await;
+        assert!(start.elapsed() >= delay);
+    }
+}
+
+// =============================================================================
+// Pagination Tests
+// =============================================================================
+
+fn mock_client(server: &wiremock::MockServer) -> SemanticScholar {
+    SemanticScholar::builder()
+        .api_key("")
+        .base_url(&server.uri())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_page_window() {
+    assert_eq!(pagination::page_window(0, 1000), Some((0, 1000)));
+    assert_eq!(pagination::page_window(0, 5000), Some((0, 1000)));
+    assert_eq!(pagination::page_window(0, 0), Some((0, 1)));
+    assert_eq!(pagination::page_window(9500, 1000), Some((9500, 500)));
+    assert_eq!(pagination::page_window(10_000, 1000), None);
+}
+
+#[tokio::test]
+async fn test_citations_stream_follows_next() {
+    use futures::TryStreamExt;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    let citation = |id: &str| serde_json::json!({"citingPaper": {"paperId": id}});
+    Mock::given(method("GET"))
+        .and(path("/paper/xxx/citations"))
+        .and(query_param("offset", "0"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "offset": 0,
+            "next": 2,
+            "data": [citation("a"), citation("b")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/paper/xxx/citations"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "offset": 2,
+            "data": [citation("c")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("xxx").limit(2);
+    let citations = ss
+        .citations_stream(query_params)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    let ids = citations
+        .iter()
+        .map(|c| c.citing_paper.clone().unwrap().paper_id.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["a", "b", "c"]);
+}
+
+#[tokio::test]
+async fn test_search_authors_stream_stops_early_and_surfaces_errors() {
+    use futures::{StreamExt, TryStreamExt};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/author/search"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "offset": 0,
+            "next": 2,
+            "total": 10,
+            "data": [{"authorId": "1"}, {"authorId": "2"}]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/author/search"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params.query_text("hinton");
+
+    // `take` stops before the second page is requested
+    let authors = ss
+        .search_authors_stream(query_params.clone())
+        .take(2)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(authors.len(), 2);
+
+    let result = ss
+        .search_authors_stream(query_params)
+        .try_collect::<Vec<_>>()
+        .await;
+    assert!(matches!(result, Err(Error::Server(_))));
+}
+
+// =============================================================================
+// Bulk Search Tests
+// =============================================================================
+
+#[test]
+fn test_paper_ids_null_token() {
+    let json = r#"{"total": 1, "token": null, "data": [{"paperId": "a"}]}"#;
+    let response: PaperIds = serde_json::from_str(json).unwrap();
+    assert_eq!(response.token, "");
+    assert_eq!(response.data.len(), 1);
+}
+
+#[test]
+fn test_get_url_bulk_search() {
+    let ss = SemanticScholar::builder()
+        .api_key("")
+        .base_url("http://localhost:8080/graph/v1")
+        .build()
+        .unwrap();
+    let mut query_params = QueryParams::default();
+    query_params
+        .query_text("fish ladder")
+        .sort("citationCount:desc")
+        .token("NEXT");
+    let url = ss
+        .get_url(Endpoint::SearchPapersInBulk, &mut query_params)
+        .unwrap();
+    assert_eq!(
+        url,
+        "http://localhost:8080/graph/v1/paper/search/bulk?query=fish%20ladder&token=NEXT&sort=citationCount:desc"
+    );
+}
+
+#[tokio::test]
+async fn test_bulk_search_papers_stream_follows_token() {
+    use futures::TryStreamExt;
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/paper/search/bulk"))
+        .and(query_param("query", "fish"))
+        .and(query_param_is_missing("token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total": 3,
+            "token": "NEXT",
+            "data": [{"paperId": "a"}, {"paperId": "b"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/paper/search/bulk"))
+        .and(query_param("token", "NEXT"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total": 3,
+            "token": null,
+            "data": [{"paperId": "c"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params.query_text("fish");
+    let papers = ss
+        .bulk_search_papers_stream(query_params)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    let ids = papers
+        .into_iter()
+        .map(|p| p.paper_id.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["a", "b", "c"]);
+}
+
+// =============================================================================
+// Autocomplete Tests
+// =============================================================================
+
+#[test]
+fn test_autocomplete_response_deserialization() {
+    let json = r#"{
+        "matches": [
+            {
+                "id": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+                "title": "Attention is All you Need",
+                "authorsYear": "Vaswani et al., 2017"
+            },
+            {"id": "xxx", "title": "Attention Is All You Need In Speech Separation"}
+        ]
+    }"#;
+    let response: AutocompleteResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(response.matches.len(), 2);
+    assert_eq!(
+        response.matches[0].id,
+        "204e3073870fae3d05bcbc2f6a8e263d9b72e776"
+    );
+    assert_eq!(
+        response.matches[0].authors_year.as_deref(),
+        Some("Vaswani et al., 2017")
+    );
+    assert!(response.matches[1].authors_year.is_none());
+}
+
+#[tokio::test]
+async fn test_autocomplete_papers() {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/paper/autocomplete"))
+        .and(query_param("query", "attention is"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "matches": [{"id": "a", "title": "Attention Is All You Need", "authorsYear": "Vaswani et al., 2017"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let matches = ss.autocomplete_papers("attention is").await.unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].title, "Attention Is All You Need");
+}
+
+// =============================================================================
+// Batch Author Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_bulk_query_authors_chunks_and_aligns() {
+    use wiremock::matchers::{body_partial_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let author_ids = (0..crate::MAX_AUTHOR_BATCH_SIZE + 2)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>();
+    let first_chunk = (0..crate::MAX_AUTHOR_BATCH_SIZE)
+        .map(|i| serde_json::json!({"authorId": i.to_string()}))
+        .collect::<Vec<_>>();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/author/batch"))
+        .and(query_param("fields", "name"))
+        .and(body_partial_json(
+            serde_json::json!({"ids": author_ids[..crate::MAX_AUTHOR_BATCH_SIZE]}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_chunk))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/author/batch"))
+        .and(body_partial_json(
+            serde_json::json!({"ids": author_ids[crate::MAX_AUTHOR_BATCH_SIZE..]}),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([null, {"authorId": "1001"}])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let authors = ss
+        .bulk_query_authors(
+            author_ids.iter().map(String::as_str).collect(),
+            vec![AuthorField::Name],
+        )
+        .await
+        .unwrap();
+    assert_eq!(authors.len(), author_ids.len());
+    assert_eq!(authors[999].clone().unwrap().author_id.unwrap(), "999");
+    assert!(authors[1000].is_none());
+    assert_eq!(authors[1001].clone().unwrap().author_id.unwrap(), "1001");
+}
+
+// =============================================================================
+// Batch Paper Tests
+// =============================================================================
+
+#[test]
+fn test_builder_batch_concurrency() {
+    let ss = SemanticScholar::builder().api_key("").build().unwrap();
+    assert_eq!(ss.batch_concurrency(), 1);
+    let ss = SemanticScholar::builder()
+        .api_key("")
+        .batch_concurrency(0)
+        .build()
+        .unwrap();
+    assert_eq!(ss.batch_concurrency(), 1);
+}
+
+#[tokio::test]
+async fn test_bulk_query_by_ids_chunks_concurrently_and_aligns() {
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let paper_ids = (0..crate::MAX_PAPER_BATCH_SIZE * 2 + 1)
+        .map(|i| format!("p{}", i))
+        .collect::<Vec<_>>();
+    let server = MockServer::start().await;
+    for (i, chunk) in paper_ids.chunks(crate::MAX_PAPER_BATCH_SIZE).enumerate() {
+        // Unknown ids come back as `null`; the first chunk answers last
+        let data = chunk
+            .iter()
+            .map(|id| {
+                if id == "p1" || id == "p1000" {
+                    serde_json::Value::Null
+                } else {
+                    serde_json::json!({"paperId": id})
+                }
+            })
+            .collect::<Vec<_>>();
+        let delay = std::time::Duration::from_millis(if i == 0 { 200 } else { 0 });
+        Mock::given(method("POST"))
+            .and(path("/paper/batch"))
+            .and(body_partial_json(serde_json::json!({"ids": chunk})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(data)
+                    .set_delay(delay),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let mut ss = SemanticScholar::builder()
+        .api_key("")
+        .base_url(&server.uri())
+        .retry_policy(RetryPolicy::none())
+        .batch_concurrency(3)
+        .build()
+        .unwrap();
+    let papers = ss
+        .bulk_query_by_ids(paper_ids.iter().map(String::as_str).collect(), vec![])
+        .await
+        .unwrap();
+    assert_eq!(papers.len(), paper_ids.len());
+    for (id, paper) in paper_ids.iter().zip(&papers) {
+        match paper {
+            Some(paper) => assert_eq!(paper.paper_id.as_ref(), Some(id)),
+            None => assert!(id == "p1" || id == "p1000"),
+        }
+    }
+    assert_eq!(papers.iter().filter(|p| p.is_none()).count(), 2);
+}
+
+#[tokio::test]
+async fn test_bulk_query_by_ids_rejects_length_mismatch() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/paper/batch"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{"paperId": "a"}])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let error = ss
+        .bulk_query_by_ids(vec!["a", "b"], vec![])
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::BatchLengthMismatch {
+            expected: 2,
+            actual: 1,
+            ..
+        }
+    ));
+    assert_eq!(error.endpoint(), Some(&Endpoint::GetMultiplePpaerDetails));
+}
+
+// =============================================================================
+// Recommendations Tests
+// =============================================================================
+
+#[test]
+fn test_recommendation_pool_to_string() {
+    assert_eq!(RecommendationPool::Recent.to_string(), "recent");
+    assert_eq!(RecommendationPool::AllCs.to_string(), "all-cs");
+}
+
+#[test]
+fn test_recommendations_response_deserialization() {
+    let json = r#"{"recommendedPapers": [{"paperId": "a", "title": "A"}, {"paperId": "b"}]}"#;
+    let response: RecommendationsResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(response.recommended_papers.len(), 2);
+    assert_eq!(response.recommended_papers[0].title.as_deref(), Some("A"));
+}
+
+#[tokio::test]
+async fn test_recommend_for_paper() {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/recommendations/v1/papers/forpaper/xxx"))
+        .and(query_param("from", "all-cs"))
+        .and(query_param("limit", "2"))
+        .and(query_param("fields", "title"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "recommendedPapers": [{"paperId": "a"}, {"paperId": "b"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = SemanticScholar::builder()
+        .api_key("")
+        .recommendations_base_url(&format!("{}/recommendations/v1", server.uri()))
+        .build()
+        .unwrap();
+    let papers = ss
+        .recommend_for_paper("xxx", RecommendationPool::AllCs, 2, vec![PaperField::Title])
+        .await
+        .unwrap();
+    assert_eq!(papers.len(), 2);
+}
+
+#[tokio::test]
+async fn test_recommend_from_seeds() {
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/recommendations/v1/papers"))
+        .and(query_param("limit", "5"))
+        .and(body_json(serde_json::json!({
+            "positivePaperIds": ["a", "b"],
+            "negativePaperIds": ["c"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "recommendedPapers": [{"paperId": "d"}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = SemanticScholar::builder()
+        .api_key("")
+        .recommendations_base_url(&format!("{}/recommendations/v1", server.uri()))
+        .build()
+        .unwrap();
+    let papers = ss
+        .recommend_from_seeds(vec!["a", "b"], vec!["c"], 5, vec![])
+        .await
+        .unwrap();
+    assert_eq!(papers[0].paper_id.as_deref(), Some("d"));
+}
+
+// =============================================================================
+// Datasets API Tests
+// =============================================================================
+
+fn datasets_client(server: &wiremock::MockServer) -> SemanticScholar {
+    SemanticScholar::builder()
+        .api_key("key")
+        .rate_limit(None)
+        .datasets_base_url(&format!("{}/datasets/v1", server.uri()))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_get_url_rejects_dataset_endpoints() {
+    let ss = SemanticScholar::builder().api_key("").build().unwrap();
+    for endpoint in [
+        Endpoint::ListReleases,
+        Endpoint::GetRelease,
+        Endpoint::GetDataset,
+        Endpoint::GetDatasetDiffs,
+    ] {
+        let mut params = QueryParams::default();
+        assert!(matches!(
+            ss.get_url(endpoint, &mut params),
+            Err(Error::Config(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_list_releases_and_get_release() {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/datasets/v1/release/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!(["2023-03-14", "2023-03-21"])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/datasets/v1/release/2023-03-21"))
+        .and(header("x-api-key", "key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "release_id": "2023-03-21",
+            "README": "Semantic Scholar Academic Graph",
+            "datasets": [
+                {"name": "papers", "description": "Core paper metadata", "README": "..."},
+                {"name": "abstracts", "description": "Paper abstracts"}
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let mut ss = datasets_client(&server);
+    let releases = ss.list_releases().await.unwrap();
+    assert_eq!(releases, vec!["2023-03-14", "2023-03-21"]);
+
+    let release = ss.get_release("2023-03-21").await.unwrap();
+    assert_eq!(release.release_id, "2023-03-21");
+    assert_eq!(release.datasets.len(), 2);
+    assert_eq!(release.datasets[0].name, "papers");
+    assert!(release.datasets[1].readme.is_none());
+}
+
+#[tokio::test]
+async fn test_get_dataset_and_diffs() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/datasets/v1/release/latest/dataset/papers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "papers",
+            "description": "Core paper metadata",
+            "README": "...",
+            "files": ["https://s3/papers-0.jsonl.gz", "https://s3/papers-1.jsonl.gz"]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/datasets/v1/diffs/2023-08-01/to/latest/papers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "dataset": "papers",
+            "start_release": "2023-08-01",
+            "end_release": "2023-08-15",
+            "diffs": [
+                {
+                    "from_release": "2023-08-01",
+                    "to_release": "2023-08-08",
+                    "update_files": ["https://s3/u0.jsonl.gz"],
+                    "delete_files": ["https://s3/d0.jsonl.gz"]
+                },
+                {
+                    "from_release": "2023-08-08",
+                    "to_release": "2023-08-15",
+                    "update_files": ["https://s3/u1.jsonl.gz"],
+                    "delete_files": []
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let mut ss = datasets_client(&server);
+    let dataset = ss.get_dataset("latest", "papers").await.unwrap();
+    assert_eq!(dataset.files.len(), 2);
+
+    let diffs = ss
+        .get_dataset_diffs("2023-08-01", "latest", "papers")
+        .await
+        .unwrap();
+    assert_eq!(diffs.end_release, "2023-08-15");
+    assert_eq!(diffs.diffs.len(), 2);
+    assert_eq!(diffs.diffs[0].to_release, diffs.diffs[1].from_release);
+    assert!(diffs.diffs[1].delete_files.is_empty());
+}
+
+// =============================================================================
+// Dataset Reader Tests
+// =============================================================================
+
+fn write_gzipped(dir: &tempfile::TempDir, name: &str, content: &str) -> std::path::PathBuf {
+    use std::io::Write;
+    let path = dir.path().join(name);
+    let mut encoder = flate2::write::GzEncoder::new(
+        std::fs::File::create(&path).unwrap(),
+        flate2::Compression::default(),
+    );
+    encoder.write_all(content.as_bytes()).unwrap();
+    encoder.finish().unwrap();
+    path
+}
+
+#[test]
+fn test_read_papers_from_gzipped_shard() {
+    let dir = tempfile::tempdir().unwrap();
+    let content = r#"{"corpusid": 215416146, "title": "S2ORC", "year": 2020, "citationcount": 300, "referencecount": 50, "isopenaccess": true, "publicationdate": "2020-07-01", "externalids": {"CorpusId": "215416146", "DOI": "10.18653/v1/2020.acl-main.447"}, "s2fieldsofstudy": [{"category": "Computer Science", "source": "s2-fos-model"}], "authors": [{"authorId": "46258841", "name": "Kyle Lo"}]}
+
+{"corpusId": 1, "title": "camelCase works too", "citationCount": 1}
+"#;
+    let path = write_gzipped(&dir, "papers-0.jsonl.gz", content);
+    let papers = crate::datasets::read_papers(&path)
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(papers.len(), 2);
+    let paper = &papers[0];
+    assert_eq!(paper.corpus_id, Some(CorpusId(215416146)));
+    assert_eq!(paper.citation_count, Some(300));
+    assert_eq!(paper.reference_count, Some(50));
+    assert_eq!(paper.is_open_access, Some(true));
+    assert_eq!(paper.publication_date.as_deref(), Some("2020-07-01"));
+    let external_ids = paper.external_ids.as_ref().unwrap();
+    assert_eq!(external_ids.corpus_id, Some(CorpusId(215416146)));
+    assert_eq!(
+        paper.s2_fields_of_study.as_ref().unwrap()[0]
+            .category
+            .as_deref(),
+        Some("Computer Science")
+    );
+    assert_eq!(papers[1].citation_count, Some(1));
+}
+
+#[test]
+fn test_read_authors_and_citations_from_plain_jsonl() {
+    let dir = tempfile::tempdir().unwrap();
+    let authors = dir.path().join("authors.jsonl");
+    std::fs::write(
+        &authors,
+        r#"{"authorid": "1741101", "name": "Oren Etzioni", "papercount": 500, "citationcount": 40000, "hindex": 90}"#,
+    )
+    .unwrap();
+    let authors = crate::datasets::read_authors(&authors)
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(authors[0].author_id.as_deref(), Some("1741101"));
+    assert_eq!(authors[0].hindex, Some(90));
+
+    let dir = tempfile::tempdir().unwrap();
+    let content = r#"{"citationid": 1, "citingcorpusid": 10, "citedcorpusid": 20, "isinfluential": true, "contexts": ["a", "b"], "intents": [["methodology"], []]}
+{"citationid": 2, "citingcorpusid": 11, "citedcorpusid": null, "isinfluential": false, "contexts": null, "intents": ["background"]}
+"#;
+    let path = write_gzipped(&dir, "citations.jsonl.gz", content);
+    let citations = crate::datasets::read_citations(&path)
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(citations[0].cited_corpus_id, Some(CorpusId(20)));
+    assert_eq!(citations[0].is_influential, Some(true));
+    assert_eq!(
+        citations[0].intents,
+        Some(vec![vec!["methodology".to_string()], vec![]])
+    );
+    assert!(citations[1].cited_corpus_id.is_none());
+    assert_eq!(
+        citations[1].intents,
+        Some(vec![vec!["background".to_string()]])
+    );
+}
+
+#[test]
+fn test_dataset_reader_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_gzipped(
+        &dir,
+        "tldrs.jsonl.gz",
+        "{\"corpusid\": 1, \"model\": \"tldr@v2.0.0\", \"text\": \"ok\"}\nnot json\n",
+    );
+    let mut tldrs = crate::datasets::read_tldrs(&path).unwrap();
+    assert_eq!(tldrs.next().unwrap().unwrap().text.as_deref(), Some("ok"));
+    match tldrs.next().unwrap() {
+        Err(Error::Record { line, .. }) => assert_eq!(line, 2),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(tldrs.next().is_none());
+
+    assert!(matches!(
+        crate::datasets::read_papers(dir.path().join("missing.jsonl.gz")),
+        Err(Error::Io { .. })
+    ));
+}
+
+// =============================================================================
+// Dataset Store Tests
+// =============================================================================
+
+#[test]
+fn test_apply_diffs_to_memory_store() {
+    use crate::datasets::store::{apply_diffs, DiffFiles, DiffStats, MemoryStore};
+
+    let dir = tempfile::tempdir().unwrap();
+    let release = write_gzipped(
+        &dir,
+        "papers.jsonl.gz",
+        "{\"corpusid\": 1, \"title\": \"one\"}\n{\"corpusid\": 2, \"title\": \"two\"}\n{\"corpusid\": 3, \"title\": \"three\"}\n",
+    );
+    let mut store = MemoryStore::<Paper>::new();
+    assert_eq!(
+        store
+            .load(crate::datasets::read_papers(&release).unwrap())
+            .unwrap(),
+        3
+    );
+
+    // First diff updates 1 and adds 4, deletes 2; the second one deletes 4 again
+    let diffs = vec![
+        DiffFiles {
+            update_files: vec![write_gzipped(
+                &dir,
+                "update-0.jsonl.gz",
+                "{\"corpusid\": 1, \"title\": \"one v2\"}\n{\"corpusid\": 4, \"title\": \"four\"}\n",
+            )],
+            delete_files: vec![write_gzipped(
+                &dir,
+                "delete-0.jsonl.gz",
+                "{\"corpusid\": 2}\n",
+            )],
+        },
+        DiffFiles {
+            update_files: vec![],
+            delete_files: vec![write_gzipped(
+                &dir,
+                "delete-1.jsonl.gz",
+                "{\"corpusid\": 4}\n",
+            )],
+        },
+    ];
+    let stats = apply_diffs(&mut store, &diffs).unwrap();
+
+    assert_eq!(
+        stats,
+        DiffStats {
+            upserted: 2,
+            deleted: 2
+        }
+    );
+    assert_eq!(store.len(), 2);
+    assert_eq!(
+        store.get(&CorpusId(1)).unwrap().title.as_deref(),
+        Some("one v2")
+    );
+    assert!(store.get(&CorpusId(2)).is_none());
+    assert_eq!(
+        store.get(&CorpusId(3)).unwrap().title.as_deref(),
+        Some("three")
+    );
+    assert!(store.get(&CorpusId(4)).is_none());
+}
+
+#[test]
+fn test_apply_diff_to_author_store() {
+    use crate::datasets::store::{apply_diff, DiffFiles, MemoryStore};
+
+    let dir = tempfile::tempdir().unwrap();
+    let mut store = MemoryStore::<Author>::new();
+    let diff = DiffFiles {
+        update_files: vec![write_gzipped(
+            &dir,
+            "authors.jsonl.gz",
+            "{\"authorid\": \"a\", \"name\": \"A\"}\n{\"name\": \"no id\"}\n",
+        )],
+        delete_files: vec![],
+    };
+    let stats = apply_diff(&mut store, &diff).unwrap();
+    assert_eq!(stats.upserted, 1);
+    assert_eq!(
+        store.get(&"a".to_string()).unwrap().name.as_deref(),
+        Some("A")
+    );
+}
+
+// =============================================================================
+// Snippet Search Tests
+// =============================================================================
+
+#[test]
+fn test_query_params_build_with_paper_ids() {
+    let mut query_params = QueryParams::default();
+    query_params.paper_ids(vec!["CorpusId:13756489", "ARXIV:1706.03762"]);
+    assert_eq!(
+        query_params.build(),
+        "?paperIds=CorpusId%3A13756489,ARXIV%3A1706%2E03762"
+    );
+}
+
+#[test]
+fn test_snippet_search_response_deserialization() {
+    let json = r#"{
+        "data": [
+            {
+                "snippet": {
+                    "text": "An attention function can be described as mapping a query...",
+                    "snippetKind": "body",
+                    "section": "Scaled Dot-Product Attention",
+                    "snippetOffset": {"start": 10234, "end": 10712}
+                },
+                "score": 0.71,
+                "paper": {
+                    "corpusId": "13756489",
+                    "title": "Attention is All you Need",
+                    "authors": ["Ashish Vaswani", "Noam Shazeer"]
+                }
+            }
+        ],
+        "retrievalVersion": "pa1-v1"
+    }"#;
+    let response: SnippetSearchResponse = serde_json::from_str(json).unwrap();
+    let result = &response.data[0];
+    assert_eq!(
+        result.snippet.section.as_deref(),
+        Some("Scaled Dot-Product Attention")
+    );
+    let offset = result.snippet.snippet_offset.as_ref().unwrap();
+    assert_eq!((offset.start, offset.end), (Some(10234), Some(10712)));
+    assert_eq!(result.score, Some(0.71));
+    assert_eq!(result.paper.corpus_id, Some(CorpusId(13756489)));
+    assert_eq!(result.paper.authors.len(), 2);
+    assert_eq!(response.retrieval_version.as_deref(), Some("pa1-v1"));
+}
+
+#[tokio::test]
+async fn test_search_snippets() {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/snippet/search"))
+        .and(query_param("query", "attention"))
+        .and(query_param("year", "2017-"))
+        .and(query_param("paperIds", "a,b"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [{"snippet": {"text": "..."}, "score": 0.5, "paper": {"corpusId": "1"}}]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params
+        .query_text("attention")
+        .year(YearRange::since(2017))
+        .paper_ids(vec!["a", "b"]);
+    let response = ss.search_snippets(query_params).await.unwrap();
+    assert_eq!(response.data.len(), 1);
+    assert_eq!(response.data[0].paper.corpus_id, Some(CorpusId(1)));
+}
+
+// =============================================================================
+// TLDR Tests
+// =============================================================================
+
+#[test]
+fn test_paper_deserialization_with_tldr() {
+    let json = r#"{
+        "paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+        "tldr": {
+            "model": "tldr@v2.0.0",
+            "text": "A new simple network architecture, the Transformer, based solely on attention mechanisms."
+        }
+    }"#;
+    let paper = serde_json::from_str::<Paper>(json).unwrap();
+    let tldr = paper.tldr.unwrap();
+    assert_eq!(tldr.model.as_deref(), Some("tldr@v2.0.0"));
+    assert!(tldr.text.unwrap().starts_with("A new simple network"));
+
+    // Papers without a TLDR come back with `"tldr": null`
+    let paper = serde_json::from_str::<Paper>(r#"{"paperId": "abc", "tldr": null}"#).unwrap();
+    assert!(paper.tldr.is_none());
+}
+
+#[test]
+fn test_query_params_build_with_tldr() {
+    let mut params = QueryParams::default();
+    params.fields(vec![PaperField::Title, PaperField::Tldr]);
+    assert_eq!(params.build(), "?fields=title,tldr");
+}
+
+// =============================================================================
+// Model Completeness Tests
+// =============================================================================
+
+#[test]
+fn test_paper_deserialization_with_full_schema() {
+    let json = r#"{
+        "paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+        "textAvailability": "fulltext",
+        "isInfluential": true,
+        "publicationVenue": {
+            "id": "d9720b90-d60b-48bc-9df8-87a30b9a60dd",
+            "name": "Neural Information Processing Systems",
+            "type": "conference",
+            "issn": "1049-5258",
+            "alternate_issns": ["2345-6789"],
+            "alternate_urls": ["https://nips.cc/"],
+            "publisher": "Curran Associates"
+        },
+        "openAccessPdf": {
+            "url": "https://arxiv.org/pdf/1706.03762",
+            "status": "GREEN",
+            "license": "CCBY",
+            "disclaimer": "Notice: This paper is retrieved from arXiv."
+        },
+        "externalIds": {"MedLine": "12345", "ACL": "P17-1001"}
+    }"#;
+    let paper = serde_json::from_str::<Paper>(json).unwrap();
+    assert_eq!(paper.text_availability.as_deref(), Some("fulltext"));
+    assert_eq!(paper.is_influential, Some(true));
+
+    let venue = paper.publication_venue.unwrap();
+    assert_eq!(venue.issn.as_deref(), Some("1049-5258"));
+    assert_eq!(venue.alternate_issns.unwrap(), vec!["2345-6789"]);
+    assert_eq!(venue.alternate_urls.unwrap(), vec!["https://nips.cc/"]);
+    assert_eq!(venue.publisher.as_deref(), Some("Curran Associates"));
+    assert!(venue.extra.is_empty());
+
+    let pdf = paper.open_access_pdf.unwrap();
+    assert_eq!(pdf.license.as_deref(), Some("CCBY"));
+    assert!(pdf.disclaimer.unwrap().starts_with("Notice"));
+
+    let external_ids = paper.external_ids.unwrap();
+    assert_eq!(external_ids.medline.as_deref(), Some("12345"));
+    assert_eq!(external_ids.acl.as_deref(), Some("P17-1001"));
+    assert!(paper.extra.is_empty());
+}
+
+#[test]
+fn test_author_deserialization_with_full_schema() {
+    let json = r#"{
+        "authorId": "1741101",
+        "name": "Oren Etzioni",
+        "aliases": ["O. Etzioni", "Oren Etzioni"],
+        "externalIds": {"DBLP": ["Oren Etzioni"], "ORCID": "0000-0002-1825-0097"},
+        "papers": [{"paperId": "abc", "title": "Open Information Extraction"}]
+    }"#;
+    let author = serde_json::from_str::<Author>(json).unwrap();
+    assert_eq!(author.aliases.unwrap().len(), 2);
+    let external_ids = author.external_ids.unwrap();
+    assert_eq!(external_ids.dblp.unwrap(), vec!["Oren Etzioni"]);
+    assert_eq!(external_ids.orcid.as_deref(), Some("0000-0002-1825-0097"));
+    let papers = author.papers.unwrap();
+    assert_eq!(
+        papers[0].title.as_deref(),
+        Some("Open Information Extraction")
+    );
+    assert!(author.extra.is_empty());
+}
+
+#[test]
+fn test_unknown_fields_are_kept_in_extra() {
+    let json = r#"{
+        "paperId": "abc",
+        "brandNewField": {"nested": [1, 2]},
+        "openAccessPdf": {"url": "https://example.com/a.pdf", "newPdfField": "x"},
+        "authors": [{"authorId": "1", "newAuthorField": 42}]
+    }"#;
+    let paper = serde_json::from_str::<Paper>(json).unwrap();
+    assert_eq!(
+        paper.extra.get("brandNewField"),
+        Some(&serde_json::json!({"nested": [1, 2]}))
+    );
+    assert_eq!(
+        paper
+            .open_access_pdf
+            .as_ref()
+            .unwrap()
+            .extra
+            .get("newPdfField"),
+        Some(&serde_json::json!("x"))
+    );
+    assert_eq!(
+        paper.authors.as_ref().unwrap()[0]
+            .extra
+            .get("newAuthorField"),
+        Some(&serde_json::json!(42))
+    );
+
+    // Unknown fields survive a round trip
+    let value = serde_json::to_value(&paper).unwrap();
+    assert_eq!(
+        value["brandNewField"],
+        serde_json::json!({"nested": [1, 2]})
+    );
+    assert_eq!(value["authors"][0]["newAuthorField"], serde_json::json!(42));
+}
+
+#[test]
+fn test_unknown_external_ids_are_kept_in_extra() {
+    let json = r#"{
+        "paperId": "abc",
+        "externalIds": {"DOI": "10.1/x", "OpenAlex": "W123"},
+        "authors": [{"authorId": "1", "externalIds": {"ORCID": "0000", "Scopus": "987"}}]
+    }"#;
+    let paper = serde_json::from_str::<Paper>(json).unwrap();
+    let external_ids = paper.external_ids.as_ref().unwrap();
+    assert_eq!(external_ids.doi.as_deref(), Some("10.1/x"));
+    assert_eq!(
+        external_ids.extra.get("OpenAlex"),
+        Some(&serde_json::json!("W123"))
+    );
+    let author_ids = paper.authors.as_ref().unwrap()[0]
+        .external_ids
+        .as_ref()
+        .unwrap();
+    assert_eq!(author_ids.orcid.as_deref(), Some("0000"));
+    assert_eq!(
+        author_ids.extra.get("Scopus"),
+        Some(&serde_json::json!("987"))
+    );
+
+    let value = serde_json::to_value(&paper).unwrap();
+    assert_eq!(value["externalIds"]["OpenAlex"], serde_json::json!("W123"));
+    assert_eq!(
+        value["authors"][0]["externalIds"]["Scopus"],
+        serde_json::json!("987")
+    );
+    let paper = serde_json::from_value::<Paper>(value).unwrap();
+    assert_eq!(
+        paper.external_ids.unwrap().extra.get("OpenAlex"),
+        Some(&serde_json::json!("W123"))
+    );
+}
+
+#[test]
+fn test_new_field_names() {
+    assert_eq!(PaperField::TextAvailability.to_string(), "textAvailability");
+    assert_eq!(AuthorField::ExternalIds.to_string(), "externalIds");
+    assert_eq!(AuthorField::Aliases.to_string(), "aliases");
+    assert_eq!(
+        AuthorField::Papers(vec![PaperField::Title, PaperField::Year]).to_string(),
+        "papers.title,papers.year"
+    );
+}
+
+// =============================================================================
+// CorpusId Tests
+// =============================================================================
+
+#[test]
+fn test_corpus_id_deserialization() {
+    let above_u32 = u64::from(u32::MAX) + 1;
+    let id = serde_json::from_str::<CorpusId>(&above_u32.to_string()).unwrap();
+    assert_eq!(id, CorpusId(above_u32));
+    let id = serde_json::from_str::<CorpusId>(&format!("\"{}\"", above_u32)).unwrap();
+    assert_eq!(id.get(), above_u32);
+    assert!(serde_json::from_str::<CorpusId>("-1").is_err());
+    assert!(serde_json::from_str::<CorpusId>("\"abc\"").is_err());
+
+    assert_eq!(serde_json::to_string(&CorpusId(42)).unwrap(), "42");
+    assert_eq!("42".parse::<CorpusId>().unwrap(), CorpusId(42));
+    assert_eq!(CorpusId(42).to_string(), "42");
+    assert_eq!(u64::from(CorpusId(42)), 42);
+}
+
+#[test]
+fn test_paper_and_author_with_64bit_ids_and_counts() {
+    let json = r#"{
+        "paperId": "abc",
+        "corpusId": 5000000000,
+        "citationCount": 5000000001,
+        "externalIds": {"CorpusId": "5000000000"}
+    }"#;
+    let paper = serde_json::from_str::<Paper>(json).unwrap();
+    assert_eq!(paper.corpus_id, Some(CorpusId(5_000_000_000)));
+    assert_eq!(paper.citation_count, Some(5_000_000_001));
+    assert_eq!(paper.external_ids.unwrap().corpus_id, paper.corpus_id);
+
+    let json = r#"{"authorId": "1", "citationCount": 5000000000, "hIndex": 300}"#;
+    let author = serde_json::from_str::<Author>(json).unwrap();
+    assert_eq!(author.citation_count, Some(5_000_000_000));
+    assert_eq!(author.hindex, Some(300));
+}
+
+// =============================================================================
+// PaperId Tests
+// =============================================================================
+
+#[test]
+fn test_paper_id_parse_prefixed() {
+    let cases = [
+        ("CorpusId:215416146", PaperId::CorpusId(CorpusId(215416146))),
+        ("corpusid:215416146", PaperId::CorpusId(CorpusId(215416146))),
+        (
+            "DOI:10.18653/V1/N18-3011",
+            PaperId::Doi("10.18653/v1/n18-3011".to_string()),
+        ),
+        ("ARXIV:2106.15928", PaperId::Arxiv("2106.15928".to_string())),
+        (
+            "arXiv:2106.15928v3",
+            PaperId::Arxiv("2106.15928".to_string()),
+        ),
+        ("MAG:112218234", PaperId::Mag("112218234".to_string())),
+        ("ACL:W12-3903", PaperId::Acl("W12-3903".to_string())),
+        ("PMID:19872477", PaperId::Pmid("19872477".to_string())),
+        ("PMCID:2323736", PaperId::Pmcid("2323736".to_string())),
+        (
+            "URL:https://aclanthology.org/W12-3903",
+            PaperId::Url("https://aclanthology.org/W12-3903".to_string()),
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(input.parse::<PaperId>().unwrap(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_paper_id_parse_raw() {
+    let sha = "649def34f8be52c8b66281af98ae884c09aef38b";
+    let cases = [
+        (sha.to_uppercase(), PaperId::Sha(sha.to_string())),
+        (
+            "10.1145/3292500.3330701".to_string(),
+            PaperId::Doi("10.1145/3292500.3330701".to_string()),
+        ),
+        (
+            "2106.15928v2".to_string(),
+            PaperId::Arxiv("2106.15928".to_string()),
+        ),
+        (
+            "1706.03762".to_string(),
+            PaperId::Arxiv("1706.03762".to_string()),
+        ),
+        (
+            "hep-th/9901001v1".to_string(),
+            PaperId::Arxiv("hep-th/9901001".to_string()),
+        ),
+        (
+            "math.GT/0309136".to_string(),
+            PaperId::Arxiv("math.GT/0309136".to_string()),
+        ),
+        (
+            "https://arxiv.org/abs/2106.15928v2".to_string(),
+            PaperId::Arxiv("2106.15928".to_string()),
+        ),
+        (
+            "https://arxiv.org/pdf/2106.15928v2.pdf".to_string(),
+            PaperId::Arxiv("2106.15928".to_string()),
+        ),
+        (
+            "https://doi.org/10.18653/v1/N18-3011".to_string(),
+            PaperId::Doi("10.18653/v1/n18-3011".to_string()),
+        ),
+        (
+            format!(
+                "https://www.semanticscholar.org/paper/Construction-of-the-Literature/{}?utm=x",
+                sha
+            ),
+            PaperId::Sha(sha.to_string()),
+        ),
+        (
+            "https://api.semanticscholar.org/CorpusID:13756489".to_string(),
+            PaperId::CorpusId(CorpusId(13756489)),
+        ),
+        (
+            "https://aclanthology.org/W12-3903.pdf".to_string(),
+            PaperId::Url("https://aclanthology.org/W12-3903.pdf".to_string()),
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(input.parse::<PaperId>().unwrap(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_paper_id_parse_invalid() {
+    for input in [
+        "",
+        "attention is all you need",
+        "12345",
+        "FOO:bar",
+        "DOI:",
+        "CorpusId:abc",
+    ] {
+        assert!(
+            matches!(input.parse::<PaperId>(), Err(Error::InvalidPaperId(_))),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_paper_id_display_round_trip() {
+    let ids = [
+        PaperId::Sha("649def34f8be52c8b66281af98ae884c09aef38b".to_string()),
+        PaperId::CorpusId(CorpusId(215416146)),
+        PaperId::Doi("10.18653/v1/n18-3011".to_string()),
+        PaperId::Arxiv("2106.15928".to_string()),
+        PaperId::Url("https://aclanthology.org/W12-3903".to_string()),
+    ];
+    for id in ids {
+        assert_eq!(id.to_string().parse::<PaperId>().unwrap(), id);
+    }
+    assert_eq!(PaperId::from(CorpusId(1)).to_string(), "CorpusId:1");
+}
+
+#[tokio::test]
+async fn test_paper_endpoints_normalize_paper_id() {
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/paper/ARXIV:2106.15928"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"paperId": "a"})))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/paper/batch"))
+        .and(body_partial_json(
+            serde_json::json!({"ids": ["ARXIV:2106.15928", "DOI:10.18653/v1/n18-3011", "not-an-id"]}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"paperId": "a"},
+            {"paperId": "b"},
+            null
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("https://arxiv.org/abs/2106.15928v2");
+    ss.query_paper_details(query_params).await.unwrap();
+
+    let mut query_params = QueryParams::default();
+    query_params.paper_id(PaperId::Arxiv("2106.15928".to_string()));
+    ss.query_paper_details(query_params).await.unwrap();
+
+    let papers = ss
+        .bulk_query_by_ids(
+            vec![
+                PaperId::Arxiv("2106.15928".to_string()).to_string(),
+                "10.18653/V1/N18-3011".to_string(),
+                "not-an-id".to_string(),
+            ],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert_eq!(papers.len(), 3);
+}
+
+#[tokio::test]
+async fn test_paper_id_is_encoded_in_path() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/paper/URL:https://example.com/paper%3Fid%3D42%20v2/citations",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"offset": 0, "data": []})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params
+        .paper_id(PaperId::Url(
+            "https://example.com/paper?id=42 v2".to_string(),
+        ))
+        .fields(vec![PaperField::Title]);
+    ss.query_paper_citations(query_params).await.unwrap();
+
+    // The `?` of the id does not leak into the query string
+    let requests = server.received_requests().await.unwrap();
+    let query = requests[0].url.query().unwrap();
+    assert!(query.starts_with("fields=title"));
+    assert!(!query.contains("id=42"));
+}
+
+// =============================================================================
+// Typed PublicationTypes / FieldsOfStudy Tests
+// =============================================================================
+
+#[test]
+fn test_publication_types_from_str() {
+    let cases = [
+        ("JournalArticle", PublicationTypes::JournalArticle),
+        ("ClinicalTrial", PublicationTypes::ClinicalTrial),
+        ("Clinical Trial", PublicationTypes::ClinicalTrial),
+        ("MetaAnalysis", PublicationTypes::MetaAnalysis),
+        ("Meta-Analysis", PublicationTypes::MetaAnalysis),
+        ("Book Section", PublicationTypes::BookSection),
+        ("Preprint", PublicationTypes::Other("Preprint".to_string())),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(input.parse::<PublicationTypes>().unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_fields_of_study_from_str() {
+    let cases = [
+        ("Computer Science", FieldsOfStudy::ComputerScience),
+        ("ComputerScience", FieldsOfStudy::ComputerScience),
+        (
+            "agricultural and food science",
+            FieldsOfStudy::AgriculturalAndFoodScience,
+        ),
+        (
+            "Agricultural and Food Sciences",
+            FieldsOfStudy::AgriculturalAndFoodScience,
+        ),
+        ("History", FieldsOfStudy::Histroy),
+        ("Astrology", FieldsOfStudy::Other("Astrology".to_string())),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(input.parse::<FieldsOfStudy>().unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_publication_types_and_fields_of_study_serde() {
+    let payload = r#"["Review","ClinicalTrial","MetaAnalysis","BookSection","Preprint"]"#;
+    let types: Vec<PublicationTypes> = serde_json::from_str(payload).unwrap();
+    assert_eq!(
+        types,
+        vec![
+            PublicationTypes::Review,
+            PublicationTypes::ClinicalTrial,
+            PublicationTypes::MetaAnalysis,
+            PublicationTypes::BookSection,
+            PublicationTypes::Other("Preprint".to_string()),
+        ]
+    );
+    assert_eq!(serde_json::to_string(&types).unwrap(), payload);
+
+    let payload = r#"["Materials Science","History","Agricultural and Food Sciences"]"#;
+    let fields: Vec<FieldsOfStudy> = serde_json::from_str(payload).unwrap();
+    assert_eq!(
+        fields,
+        vec![
+            FieldsOfStudy::MaterialsScience,
+            FieldsOfStudy::Histroy,
+            FieldsOfStudy::AgriculturalAndFoodScience,
+        ]
+    );
+    assert_eq!(serde_json::to_string(&fields).unwrap(), payload);
+}
+
+#[test]
+fn test_query_params_build_uses_api_names() {
+    let mut params = QueryParams::default();
+    params
+        .publication_types(vec![
+            PublicationTypes::ClinicalTrial,
+            PublicationTypes::MetaAnalysis,
+            PublicationTypes::BookSection,
+        ])
+        .fields_of_study(vec![
+            FieldsOfStudy::Histroy,
+            FieldsOfStudy::AgriculturalAndFoodScience,
+        ]);
+    let result = params.build();
+    assert!(result.contains("publicationTypes=ClinicalTrial,MetaAnalysis,BookSection"));
+    assert!(result.contains("fieldsOfStudy=History,Agricultural%20and%20Food%20Sciences"));
+}
+
+#[test]
+fn test_paper_typed_accessors() {
+    let json = r#"{
+        "paperId": "abc",
+        "publicationTypes": ["JournalArticle", "ClinicalTrial"],
+        "fieldsOfStudy": ["Medicine", "Biology"]
+    }"#;
+    let paper = serde_json::from_str::<Paper>(json).unwrap();
+    assert_eq!(
+        paper.publication_types(),
+        vec![
+            PublicationTypes::JournalArticle,
+            PublicationTypes::ClinicalTrial
+        ]
+    );
+    assert!(paper.has_publication_type(&PublicationTypes::ClinicalTrial));
+    assert!(!paper.has_publication_type(&PublicationTypes::MetaAnalysis));
+    assert_eq!(
+        paper.fields_of_study(),
+        vec![FieldsOfStudy::Medicine, FieldsOfStudy::Biology]
+    );
+    assert!(paper.has_field_of_study(&FieldsOfStudy::Biology));
+
+    // Not requested
+    let paper = serde_json::from_str::<Paper>(r#"{"paperId": "abc"}"#).unwrap();
+    assert!(paper.publication_types().is_empty());
+    assert!(paper.fields_of_study().is_empty());
+}
+
+// =============================================================================
+// Dates Tests
+// =============================================================================
+
+#[test]
+fn test_partial_date_parse() {
+    assert_eq!(
+        "2020".parse::<PartialDate>().unwrap(),
+        PartialDate::year(2020)
+    );
+    assert_eq!(
+        "2020-06".parse::<PartialDate>().unwrap(),
+        PartialDate::month(2020, 6).unwrap()
+    );
+    let date = "2020-02-29".parse::<PartialDate>().unwrap();
+    assert_eq!(date, PartialDate::day(2020, 2, 29).unwrap());
+    assert_eq!(date.to_string(), "2020-02-29");
+    assert!(date.is_full());
+
+    for input in [
+        "",
+        "20",
+        "2020-6",
+        "2020-13",
+        "2023-02-29",
+        "2020-04-31",
+        "2020/01/01",
+        "abcd",
+    ] {
+        assert!(
+            matches!(input.parse::<PartialDate>(), Err(Error::InvalidDate(_))),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_year_range_parse_and_display() {
+    let cases = [
+        ("2019", YearRange::year(2019)),
+        ("2016-2020", YearRange::between(2016, 2020)),
+        ("2010-", YearRange::since(2010)),
+        ("-2015", YearRange::until(2015)),
+    ];
+    for (input, expected) in cases {
+        let range = input.parse::<YearRange>().unwrap();
+        assert_eq!(range, expected);
+        assert_eq!(range.to_string(), input);
+    }
+    for input in ["", "-", "2021-2019", "19-20", "2020-06-01", "next year"] {
+        assert!(input.parse::<YearRange>().is_err(), "{}", input);
+    }
+    assert!(YearRange::since(2010).contains(2024));
+    assert!(!YearRange::between(2016, 2020).contains(2021));
+    assert_eq!(
+        YearRange::between(2020, 2016),
+        YearRange::between(2016, 2020)
+    );
+}
+
+#[test]
+fn test_date_range_parse_and_display() {
+    for input in [
+        "2019-03-05",
+        "2019-03",
+        "2019",
+        "2016-03-05:2020-06-06",
+        "1981-08-25:",
+        ":2015-01",
+        "2015:2020",
+        "2020-06:2020",
+        "2020-06-15:2020-06",
+        "2020:2020-03-01",
+    ] {
+        assert_eq!(input.parse::<DateRange>().unwrap().to_string(), input);
+    }
+    for input in [
+        "",
+        ":",
+        "2020-06-06:2016-03-05",
+        "2020-13:",
+        "2020-01-01:2020-02-30",
+        "2021:2020-06",
+        "2020-07-01:2020-06",
+    ] {
+        assert!(input.parse::<DateRange>().is_err(), "{}", input);
+    }
+    assert_eq!(DateRange::from(YearRange::since(2015)).to_string(), "2015:");
+    assert_eq!(
+        DateRange::between(
+            PartialDate::year(2015),
+            PartialDate::month(2016, 1).unwrap()
+        )
+        .to_string(),
+        "2015:2016-01"
+    );
+    assert_eq!(
+        DateRange::between(
+            PartialDate::day(2020, 6, 15).unwrap(),
+            PartialDate::month(2020, 6).unwrap()
+        )
+        .to_string(),
+        "2020-06-15:2020-06"
+    );
+    assert_eq!(
+        DateRange::between(
+            PartialDate::year(2021),
+            PartialDate::month(2020, 6).unwrap()
+        )
+        .to_string(),
+        "2020-06:2021"
+    );
+}
+
+#[test]
+fn test_query_params_build_with_typed_ranges() {
+    let mut params = QueryParams::default();
+    params
+        .year(YearRange::since(2017))
+        .publication_date_or_year(DateRange::until(PartialDate::month(2020, 6).unwrap()));
+    let result = params.build();
+    assert!(result.contains("year=2017-"));
+    assert!(result.contains("publicationDateOrYear=:2020-06"));
+}
+
+#[test]
+fn test_paper_parsed_publication_date() {
+    let paper = serde_json::from_str::<Paper>(r#"{"publicationDate": "2017-06-12", "year": 2017}"#)
+        .unwrap();
+    assert_eq!(
+        paper.parsed_publication_date(),
+        PartialDate::day(2017, 6, 12)
+    );
+
+    // Falls back to the year
+    let paper =
+        serde_json::from_str::<Paper>(r#"{"publicationDate": null, "year": 2017}"#).unwrap();
+    assert_eq!(
+        paper.parsed_publication_date(),
+        Some(PartialDate::year(2017))
+    );
+
+    let paper = serde_json::from_str::<Paper>(r#"{"paperId": "abc"}"#).unwrap();
+    assert!(paper.parsed_publication_date().is_none());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_paper_publication_naive_date() {
+    use chrono::NaiveDate;
+
+    let paper = serde_json::from_str::<Paper>(r#"{"publicationDate": "2017-06-12"}"#).unwrap();
+    assert_eq!(
+        paper.publication_naive_date(),
+        NaiveDate::from_ymd_opt(2017, 6, 12)
+    );
+    let date = PartialDate::try_from(NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()).unwrap();
+    assert_eq!(date, PartialDate::day(2020, 2, 29).unwrap());
+    assert!(matches!(
+        PartialDate::try_from(NaiveDate::from_ymd_opt(-1, 1, 1).unwrap()),
+        Err(Error::InvalidDate(_))
+    ));
+    assert!(PartialDate::try_from(NaiveDate::from_ymd_opt(65536, 1, 1).unwrap()).is_err());
+    assert!(PartialDate::year(2020).to_naive_date().is_none());
+}
+
+// =============================================================================
+// Field Validation Tests
+// =============================================================================
+
+#[test]
+fn test_unsupported_fields_citation_edge_fields() {
+    let fields = vec![
+        PaperField::Title,
+        PaperField::Contexts,
+        PaperField::Intents,
+        PaperField::IsInfluential,
+        PaperField::ContextsWithIntent,
+    ];
+    assert!(Endpoint::GetCitationsOfAPaper
+        .unsupported_fields(&fields)
+        .is_empty());
+    assert!(Endpoint::GetReferencesOfAPaper
+        .unsupported_fields(&fields)
+        .is_empty());
+    assert_eq!(
+        Endpoint::GetPaperDetails.unsupported_fields(&fields),
+        vec!["contexts", "intents", "isInfluential", "contextsWithIntent"]
+    );
+}
+
+#[test]
+fn test_unsupported_fields_search_endpoints() {
+    let fields = vec![
+        PaperField::Title,
+        PaperField::Embedding,
+        PaperField::Tldr,
+        PaperField::Citations(vec![PaperField::Title]),
+    ];
+    assert_eq!(
+        Endpoint::GetPapersByTitle.unsupported_fields(&fields),
+        vec!["embedding.specter_v2"]
+    );
+    assert_eq!(
+        Endpoint::SearchPapersInBulk.unsupported_fields(&fields),
+        vec!["embedding.specter_v2", "tldr", "citations.title"]
+    );
+    assert!(Endpoint::GetPaperDetails
+        .unsupported_fields(&fields)
+        .is_empty());
+    assert!(Endpoint::GetMultiplePpaerDetails
+        .unsupported_fields(&fields)
+        .is_empty());
+}
+
+#[test]
+fn test_unsupported_fields_nested() {
+    let fields = vec![
+        PaperField::Citations(vec![PaperField::Title, PaperField::Tldr]),
+        PaperField::References(vec![PaperField::Citations(vec![PaperField::Title])]),
+        PaperField::Authors(vec![
+            AuthorField::Name,
+            AuthorField::Papers(vec![PaperField::Title]),
+        ]),
+    ];
+    assert_eq!(
+        Endpoint::GetPaperDetails.unsupported_fields(&fields),
+        vec![
+            "citations.tldr",
+            "references.citations.title",
+            "authors.papers.title"
+        ]
+    );
+    // Citing / cited papers cannot carry their own citations
+    assert_eq!(
+        Endpoint::GetCitationsOfAPaper
+            .unsupported_fields(&[PaperField::Citations(vec![PaperField::Title])]),
+        vec!["citations.title"]
+    );
+    assert_eq!(
+        Endpoint::GetAuthorDetails.unsupported_author_fields(&[
+            AuthorField::Name,
+            AuthorField::Papers(vec![PaperField::Title, PaperField::Contexts]),
+        ]),
+        vec!["papers.contexts"]
+    );
+}
+
+#[test]
+fn test_query_params_validate() {
+    let mut params = QueryParams::default();
+    params.fields(vec![PaperField::Title, PaperField::Contexts]);
+    assert!(params.validate(&Endpoint::GetCitationsOfAPaper).is_ok());
+
+    let err = params.validate(&Endpoint::GetPaperDetails).unwrap_err();
+    match &err {
+        Error::UnsupportedFields { endpoint, fields } => {
+            assert_eq!(*endpoint, Endpoint::GetPaperDetails);
+            assert_eq!(fields, &vec!["contexts".to_string()]);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert_eq!(err.endpoint(), Some(&Endpoint::GetPaperDetails));
+    assert!(err.to_string().contains("contexts"));
+}
+
+#[tokio::test]
+async fn test_unsupported_fields_are_rejected_before_sending() {
+    use wiremock::MockServer;
+
+    // No mock is mounted: any request would fail with a 404
+    let server = MockServer::start().await;
+    let mut ss = mock_client(&server);
+    let mut query_params = QueryParams::default();
+    query_params
+        .query_text("attention")
+        .fields(vec![PaperField::Title, PaperField::Embedding]);
+    let err = ss.query_papers_by_title(query_params).await.unwrap_err();
+    assert!(matches!(err, Error::UnsupportedFields { .. }));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+// =============================================================================
+// PaperField / AuthorField Parsing Tests
+// =============================================================================
+
+#[test]
+fn test_paper_field_from_str() {
+    let cases = [
+        ("title", PaperField::Title),
+        ("corpusId", PaperField::Corpusid),
+        ("embedding", PaperField::Embedding),
+        ("embedding.specter_v2", PaperField::Embedding),
+        ("authors.name", PaperField::Authors(vec![AuthorField::Name])),
+        (
+            "citations.title",
+            PaperField::Citations(vec![PaperField::Title]),
+        ),
+        (
+            "references.citationCount",
+            PaperField::References(vec![PaperField::CitationCount]),
+        ),
+        (
+            "citations.title,citations.year",
+            PaperField::Citations(vec![PaperField::Title, PaperField::Year]),
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(input.parse::<PaperField>().unwrap(), expected, "{}", input);
+    }
+    for input in [
+        "",
+        "Title",
+        "embedding.specter_v1",
+        "authors.title",
+        "title,year",
+    ] {
+        assert!(
+            matches!(input.parse::<PaperField>(), Err(Error::UnknownField(_))),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_author_field_from_str() {
+    assert_eq!(
+        "hIndex".parse::<AuthorField>().unwrap(),
+        AuthorField::HIndex
+    );
+    assert_eq!(
+        "papers.title,papers.year".parse::<AuthorField>().unwrap(),
+        AuthorField::Papers(vec![PaperField::Title, PaperField::Year])
+    );
+    assert!("hindex".parse::<AuthorField>().is_err());
+}
+
+#[test]
+fn test_paper_field_parse_list_round_trip() {
+    let fields = vec![
+        PaperField::Title,
+        PaperField::Authors(vec![AuthorField::Name, AuthorField::HIndex]),
+        PaperField::Citations(vec![PaperField::Title, PaperField::Year]),
+        PaperField::Embedding,
+        PaperField::References(vec![PaperField::PaperId]),
+    ];
+    let names = fields
+        .iter()
+        .map(|field| field.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    assert_eq!(PaperField::parse_list(&names).unwrap(), fields);
+
+    let fields = PaperField::parse_list(" title , authors.name,authors.url ,year").unwrap();
+    assert_eq!(
+        fields,
+        vec![
+            PaperField::Title,
+            PaperField::Authors(vec![AuthorField::Name, AuthorField::Url]),
+            PaperField::Year,
+        ]
+    );
+    assert!(PaperField::parse_list("title,nope").is_err());
+}
+
+#[test]
+fn test_paper_and_author_field_serde() {
+    // e.g. a YAML job config deserialized into `Vec<PaperField>`
+    let fields: Vec<PaperField> = serde_json::from_str(
+        r#"["title", "authors.name", "citations.title", "embedding.specter_v2"]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        fields,
+        vec![
+            PaperField::Title,
+            PaperField::Authors(vec![AuthorField::Name]),
+            PaperField::Citations(vec![PaperField::Title]),
+            PaperField::Embedding,
+        ]
+    );
+    let json = serde_json::to_string(&fields).unwrap();
+    assert_eq!(
+        json,
+        r#"["title","authors.name","citations.title","embedding.specter_v2"]"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Vec<PaperField>>(&json).unwrap(),
+        fields
+    );
+
+    let field = AuthorField::Papers(vec![PaperField::Title, PaperField::Year]);
+    let json = serde_json::to_string(&field).unwrap();
+    assert_eq!(json, r#""papers.title,papers.year""#);
+    assert_eq!(serde_json::from_str::<AuthorField>(&json).unwrap(), field);
+    assert!(serde_json::from_str::<PaperField>(r#""bogus""#).is_err());
+}
+
+#[test]
+fn test_empty_nested_fields_round_trip() {
+    let fields = vec![
+        PaperField::Authors(vec![]),
+        PaperField::Citations(vec![]),
+        PaperField::References(vec![]),
+    ];
+    let json = serde_json::to_string(&fields).unwrap();
+    assert_eq!(json, r#"["authors","citations","references"]"#);
+    assert_eq!(
+        serde_json::from_str::<Vec<PaperField>>(&json).unwrap(),
+        fields
+    );
+
+    let field = AuthorField::Papers(vec![]);
+    let json = serde_json::to_string(&field).unwrap();
+    assert_eq!(json, r#""papers""#);
+    assert_eq!(serde_json::from_str::<AuthorField>(&json).unwrap(), field);
+
+    let mut params = QueryParams::default();
+    params.fields(vec![PaperField::Title, PaperField::Authors(vec![])]);
+    assert_eq!(params.build(), "?fields=title,authors");
+}
+
+#[test]
+fn test_paper_field_presets() {
+    let basic = PaperField::all_basic();
+    assert!(basic.contains(&PaperField::Title));
+    assert!(!basic.contains(&PaperField::Embedding));
+    assert!(!basic
+        .iter()
+        .any(|field| matches!(field, PaperField::Citations(_) | PaperField::Authors(_))));
+
+    for endpoint in [
+        Endpoint::GetPaperDetails,
+        Endpoint::GetMultiplePpaerDetails,
+        Endpoint::GetPapersByTitle,
+        Endpoint::SearchPapersInBulk,
+        Endpoint::GetCitationsOfAPaper,
+        Endpoint::GetReferencesOfAPaper,
+        Endpoint::GetAuthorPapers,
+    ] {
+        let fields = PaperField::all_for(&endpoint);
+        assert!(
+            endpoint.unsupported_fields(&fields).is_empty(),
+            "{:?}",
+            endpoint
+        );
+        assert!(fields.contains(&PaperField::Title), "{:?}", endpoint);
+    }
+    let details = PaperField::all_for(&Endpoint::GetPaperDetails);
+    assert!(details.contains(&PaperField::Tldr));
+    assert!(details.contains(&PaperField::Citations(PaperField::all_basic())));
+    assert!(!details.contains(&PaperField::Contexts));
+    let citations = PaperField::all_for(&Endpoint::GetCitationsOfAPaper);
+    assert!(citations.contains(&PaperField::Contexts));
+    assert!(!citations.contains(&PaperField::Citations(PaperField::all_basic())));
+    let bulk = PaperField::all_for(&Endpoint::SearchPapersInBulk);
+    assert!(!bulk.contains(&PaperField::Tldr));
+    assert!(!bulk.contains(&PaperField::Embedding));
+
+    let authors = AuthorField::all_for(&Endpoint::GetAuthorDetails);
+    assert!(authors.contains(&AuthorField::HIndex));
+    assert!(authors.contains(&AuthorField::Papers(PaperField::all_basic())));
+    assert!(!AuthorField::all_basic()
+        .iter()
+        .any(|field| matches!(field, AuthorField::Papers(_))));
+
+    for endpoint in [
+        Endpoint::AutocompletePapers,
+        Endpoint::ListReleases,
+        Endpoint::GetRelease,
+        Endpoint::GetDataset,
+        Endpoint::GetDatasetDiffs,
+    ] {
+        assert!(!endpoint.takes_fields(), "{:?}", endpoint);
+        assert!(PaperField::all_for(&endpoint).is_empty(), "{:?}", endpoint);
+        assert!(AuthorField::all_for(&endpoint).is_empty(), "{:?}", endpoint);
+    }
+    assert!(Endpoint::GetPaperDetails.takes_fields());
+}
@@ -39,9 +39,7 @@
 //! let mut query_params = QueryParams::default();
 //! query_params.query_text(query_text);
 //!
-//! let max_retry_count = 5;
-//! let wait_time = 10;
-//! let paper = ss.query_a_paper_by_title(query_params, max_retry_count, wait_time).await.unwrap();
+//! let paper = ss.query_a_paper_by_title(query_params).await.unwrap();
 //!
 //! assert_eq!(
 //!    paper.title.clone().unwrap().to_lowercase(),
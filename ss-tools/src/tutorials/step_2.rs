@@ -24,8 +24,8 @@
 //! | `title` | `Option<String>` | Paper title |
 //! | `abstract_text` | `Option<String>` | Paper abstract |
 //! | `year` | `Option<u32>` | Publication year |
-//! | `citation_count` | `Option<u32>` | Number of citations |
-//! | `reference_count` | `Option<u32>` | Number of references |
+//! | `citation_count` | `Option<u64>` | Number of citations |
+//! | `reference_count` | `Option<u64>` | Number of references |
 //! | `authors` | `Option<Vec<Author>>` | List of authors |
 //! | `external_ids` | `Option<ExternalIds>` | External IDs (ArXiv, DOI, etc.) |
 //! | `open_access_pdf` | `Option<OpenAccessPdf>` | Open access PDF URL |
@@ -54,7 +54,7 @@
 //! | `pubmed_central` | `Option<String>` | PubMed Central ID |
 //! | `mag` | `Option<String>` | Microsoft Academic Graph ID |
 //! | `acl` | `Option<String>` | ACL Anthology ID |
-//! | `corpus_id` | `Option<u64>` | Semantic Scholar Corpus ID |
+//! | `corpus_id` | `Option<CorpusId>` | Semantic Scholar Corpus ID |
 //!
 //! ## Author Struct
 //!
@@ -75,9 +75,9 @@
 //! | `author_id` | `Option<String>` | Unique author ID |
 //! | `name` | `Option<String>` | Author name |
 //! | `affiliations` | `Option<Vec<String>>` | Author affiliations |
-//! | `paper_count` | `Option<u32>` | Number of papers |
-//! | `citation_count` | `Option<u32>` | Total citations |
-//! | `hindex` | `Option<u32>` | H-index |
+//! | `paper_count` | `Option<u64>` | Number of papers |
+//! | `citation_count` | `Option<u64>` | Total citations |
+//! | `hindex` | `Option<u64>` | H-index |
 //!
 //! ## Field Enums
 //!
@@ -53,10 +53,27 @@
 //!     ]);
 //! ```
 //!
+//! ### Field Lists from Strings
+//!
+//! Fields parse from the API's dotted names, e.g. from a config file, and
+//! [`PaperField::all_for`](crate::structs::PaperField::all_for) selects every field an endpoint accepts.
+//!
+//! ```rust
+//! use ss_tools::QueryParams;
+//! use ss_tools::structs::{Endpoint, PaperField};
+//!
+//! let mut params = QueryParams::default();
+//! params.fields(PaperField::parse_list("title,authors.name,citations.title").unwrap());
+//! assert!(params.validate(&Endpoint::GetPaperDetails).is_ok());
+//!
+//! params.fields(PaperField::all_for(&Endpoint::SearchPapersInBulk));
+//! ```
+//!
 //! ### Filtering Papers
 //!
 //! ```rust
 //! use ss_tools::QueryParams;
+//! use ss_tools::dates::YearRange;
 //! use ss_tools::structs::{PublicationTypes, FieldsOfStudy};
 //!
 //! let mut params = QueryParams::default();
@@ -65,7 +82,7 @@
 //!     .publication_types(vec![PublicationTypes::JournalArticle])
 //!     .fields_of_study(vec![FieldsOfStudy::ComputerScience])
 //!     .min_citation_count(100)
-//!     .year("2020-2024")
+//!     .year(YearRange::between(2020, 2024))
 //!     .open_access_pdf(true);
 //! ```
 //!
@@ -142,15 +159,19 @@
 //!
 //! | Method | Description |
 //! |--------|-------------|
-//! | `paper_id(id)` | Set paper ID or author ID |
+//! | `paper_id(id)` | Set paper ID ([`PaperId`](crate::structs::PaperId) or raw string) or author ID |
 //! | `query_text(text)` | Set search query text |
 //! | `fields(fields)` | Set paper fields to retrieve |
 //! | `author_fields(fields)` | Set author fields to retrieve |
 //! | `publication_types(types)` | Filter by publication types |
 //! | `fields_of_study(fields)` | Filter by fields of study |
 //! | `min_citation_count(count)` | Minimum citation count |
-//! | `year(range)` | Year or year range (e.g., "2020-2024") |
+//! | `year(range)` | [`YearRange`](crate::dates::YearRange), e.g. `"2020-2024".parse()?` |
+//! | `publication_date_or_year(range)` | [`DateRange`](crate::dates::DateRange), e.g. `"2020-06:".parse()?` |
 //! | `open_access_pdf(bool)` | Filter for open access papers |
 //! | `offset(n)` | Pagination offset |
 //! | `limit(n)` | Maximum results |
 //! | `sort(field)` | Sort order |
+//! | `token(token)` | Continuation token of a bulk search |
+//! | `pool(pool)` | Candidate pool of paper recommendations |
+//! | `paper_ids(ids)` | Restrict a snippet search to these papers |
@@ -17,7 +17,7 @@
 //! let mut query_params = QueryParams::default();
 //! query_params.query_text("Attention Is All You Need");
 //!
-//! let paper = ss.query_a_paper_by_title(query_params, 5, 10).await?;
+//! let paper = ss.query_a_paper_by_title(query_params).await?;
 //! println!("Paper: {:?}", paper.title);
 //! # Ok(())
 //! # }
@@ -36,7 +36,7 @@
 //! let mut query_params = QueryParams::default();
 //! query_params.query_text("deep learning");
 //!
-//! let papers = ss.query_papers_by_title(query_params, 5, 10).await?;
+//! let papers = ss.query_papers_by_title(query_params).await?;
 //! println!("Found {} papers", papers.len());
 //! # Ok(())
 //! # }
@@ -61,7 +61,7 @@
 //!     PaperField::CitationCount,
 //! ]);
 //!
-//! let paper = ss.query_paper_details(query_params, 5, 10).await?;
+//! let paper = ss.query_paper_details(query_params).await?;
 //! println!("Title: {:?}", paper.title);
 //! # Ok(())
 //! # }
@@ -82,7 +82,7 @@
 //! query_params.paper_id("204e3073870fae3d05bcbc2f6a8e263d9b72e776");
 //! query_params.fields(vec![PaperField::Title, PaperField::Year]);
 //!
-//! let citations = ss.query_paper_citations(query_params, 5, 10).await?;
+//! let citations = ss.query_paper_citations(query_params).await?;
 //! println!("Found {} citations", citations.data.len());
 //! # Ok(())
 //! # }
@@ -103,7 +103,7 @@
 //! query_params.paper_id("204e3073870fae3d05bcbc2f6a8e263d9b72e776");
 //! query_params.fields(vec![PaperField::Title, PaperField::Year]);
 //!
-//! let references = ss.query_paper_references(query_params, 5, 10).await?;
+//! let references = ss.query_paper_references(query_params).await?;
 //! println!("Found {} references", references.data.len());
 //! # Ok(())
 //! # }
@@ -128,7 +128,7 @@
 //!     AuthorField::CitationCount,
 //! ]);
 //!
-//! let response = ss.query_paper_authors(query_params, 5, 10).await?;
+//! let response = ss.query_paper_authors(query_params).await?;
 //! println!("Found {} authors", response.data.len());
 //! # Ok(())
 //! # }
@@ -156,7 +156,7 @@
 //!     AuthorField::HIndex,
 //! ]);
 //!
-//! let author = ss.query_author_details(query_params, 5, 10).await?;
+//! let author = ss.query_author_details(query_params).await?;
 //! println!("Author: {:?}", author.name);
 //! # Ok(())
 //! # }
@@ -181,7 +181,7 @@
 //!     AuthorField::CitationCount,
 //! ]);
 //!
-//! let response = ss.search_authors(query_params, 5, 10).await?;
+//! let response = ss.search_authors(query_params).await?;
 //! println!("Found {} authors", response.data.len());
 //! # Ok(())
 //! # }
@@ -207,7 +207,7 @@
 //! ]);
 //! query_params.limit(10);
 //!
-//! let response = ss.query_author_papers(query_params, 5, 10).await?;
+//! let response = ss.query_author_papers(query_params).await?;
 //! println!("Found {} papers", response.data.len());
 //! # Ok(())
 //! # }
@@ -232,7 +232,35 @@
 //! let fields = vec![PaperField::Title, PaperField::CitationCount];
 //!
 //! let mut ss = SemanticScholar::new();
-//! let papers = ss.bulk_query_by_ids(paper_ids, fields, 5, 10).await?;
+//! let papers = ss.bulk_query_by_ids(paper_ids, fields).await?;
+//! println!("Retrieved {} papers", papers.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Bulk Search
+//!
+//! Page through every paper matching a boolean query using [`SemanticScholar::bulk_search_papers_stream`](crate::SemanticScholar::bulk_search_papers_stream),
+//! or fetch one batch at a time with [`SemanticScholar::bulk_search_papers`](crate::SemanticScholar::bulk_search_papers).
+//!
+//! ```rust,no_run
+//! # use anyhow::Result;
+//! # use futures::{StreamExt, TryStreamExt};
+//! # use ss_tools::{SemanticScholar, QueryParams};
+//! # use ss_tools::structs::PaperField;
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! let ss = SemanticScholar::new();
+//! let mut query_params = QueryParams::default();
+//! query_params.query_text("\"large language model\" + (reasoning | planning)");
+//! query_params.fields(vec![PaperField::Title, PaperField::CitationCount]);
+//! query_params.sort("citationCount:desc");
+//!
+//! let papers = ss
+//!     .bulk_search_papers_stream(query_params)
+//!     .take(5000)
+//!     .try_collect::<Vec<_>>()
+//!     .await?;
 //! println!("Retrieved {} papers", papers.len());
 //! # Ok(())
 //! # }
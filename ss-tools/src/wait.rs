@@ -0,0 +1,113 @@
+use crate::structs::Endpoint;
+use indicatif::ProgressBar;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A wait before retrying a failed request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaitEvent {
+    pub endpoint: Endpoint,
+    pub url: String,
+    /// The attempt that failed (1-based).
+    pub attempt: u32,
+    pub delay: Duration,
+    /// The error that caused the retry.
+    pub cause: String,
+}
+
+/// How [`SemanticScholar`](crate::SemanticScholar) reports waits between retries.
+///
+/// The default, [`WaitReporter::Tracing`], emits a `tracing` event and never writes to the
+/// terminal, which keeps daemons, log collectors and test runners clean.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyhow::Result;
+/// # use ss_tools::{SemanticScholar, WaitReporter};
+/// # fn main() -> Result<()> {
+/// let ss = SemanticScholar::builder()
+///     .wait_reporter(WaitReporter::callback(|event| {
+///         eprintln!("attempt {} failed, retrying in {:?}: {}", event.attempt, event.delay, event.cause);
+///     }))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub enum WaitReporter {
+    /// Wait without reporting anything.
+    Silent,
+    /// Emit a `WARN` level `tracing` event.
+    #[default]
+    Tracing,
+    /// Draw an `indicatif` progress bar on the terminal.
+    ProgressBar,
+    /// Call a user callback before waiting.
+    Callback(Arc<dyn Fn(&WaitEvent) + Send + Sync>),
+}
+
+impl fmt::Debug for WaitReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaitReporter::Silent => write!(f, "Silent"),
+            WaitReporter::Tracing => write!(f, "Tracing"),
+            WaitReporter::ProgressBar => write!(f, "ProgressBar"),
+            WaitReporter::Callback(_) => write!(f, "Callback(..)"),
+        }
+    }
+}
+
+impl WaitReporter {
+    pub fn callback(callback: impl Fn(&WaitEvent) + Send + Sync + 'static) -> Self {
+        WaitReporter::Callback(Arc::new(callback))
+    }
+
+    /// Report `event` and sleep for `event.delay`.
+    pub(crate) async fn wait(&self, event: WaitEvent) {
+        match self {
+            WaitReporter::Silent => tokio::time::sleep(event.delay).await,
+            WaitReporter::Tracing => {
+                tracing::warn!(
+                    endpoint = ?event.endpoint,
+                    url = %event.url,
+                    attempt = event.attempt,
+                    delay_ms = event.delay.as_millis() as u64,
+                    cause = %event.cause,
+                    "request failed, waiting before retry"
+                );
+                tokio::time::sleep(event.delay).await;
+            }
+            WaitReporter::ProgressBar => progress_bar(event.delay, &event.cause).await,
+            WaitReporter::Callback(callback) => {
+                callback(&event);
+                tokio::time::sleep(event.delay).await;
+            }
+        }
+    }
+}
+
+async fn progress_bar(delay: Duration, message: &str) {
+    let seconds = delay.as_secs();
+    let pb = ProgressBar::new(seconds);
+    pb.set_style(
+        indicatif::ProgressStyle::default_bar()
+            .template(
+                "{spinner:.green} [{elapsed_precise}] [{bar:40.green/cyan}] {pos}s/{len}s {msg}",
+            )
+            .unwrap()
+            .progress_chars("█▓▒░"),
+    );
+    if message.is_empty() {
+        pb.set_message("Waiting for the next request...");
+    } else {
+        pb.set_message(message.to_string());
+    }
+    for _ in 0..seconds {
+        pb.inc(1);
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    tokio::time::sleep(delay - Duration::from_secs(seconds)).await;
+    pb.finish_and_clear();
+}
@@ -12,17 +12,16 @@ async fn test_bulk_query_by_ids_without_fields() {
     let fields = vec![];
 
     let mut ss = SemanticScholar::new();
-    let papers = ss.bulk_query_by_ids(paper_ids, fields, 5, 10).await;
+    let papers = ss.bulk_query_by_ids(paper_ids, fields).await;
 
     match papers {
         Ok(papers) => {
             for paper in papers {
                 println!("{}", serde_json::to_string_pretty(&paper).unwrap());
             }
-            assert!(true)
         }
         Err(e) => {
-            assert!(false, "Error: {}", e.to_string())
+            panic!("Error: {}", e)
         }
     }
 }
@@ -42,17 +41,16 @@ async fn test_bulk_query_by_ids_with_fields() {
     ];
 
     let mut ss = SemanticScholar::new();
-    let papers = ss.bulk_query_by_ids(paper_ids, fields, 5, 10).await;
+    let papers = ss.bulk_query_by_ids(paper_ids, fields).await;
 
     match papers {
         Ok(papers) => {
             for paper in papers {
                 println!("{}", serde_json::to_string_pretty(&paper).unwrap());
             }
-            assert!(true)
         }
         Err(e) => {
-            assert!(false, "Error: {}", e.to_string())
+            panic!("Error: {}", e)
         }
     }
 }
@@ -66,7 +64,7 @@ async fn test_query_papers_1() {
     query_params.query_text("attention is all you need");
 
     // Execute
-    let res = ss.query_papers_by_title(query_params, 5, 10).await.unwrap();
+    let res = ss.query_papers_by_title(query_params).await.unwrap();
 
     // Verify
     assert!(res.len() > 1);
@@ -90,7 +88,7 @@ async fn test_query_papers_2() {
     let mut ss = SemanticScholar::new();
 
     // Execute
-    let res = ss.query_papers_by_title(query_params, 5, 10).await.unwrap();
+    let res = ss.query_papers_by_title(query_params).await.unwrap();
 
     // Verify
     assert!(res.len() > 1);
@@ -115,16 +113,11 @@ async fn test_query_papers_3() {
     query_params.publication_types(vec![PublicationTypes::JournalArticle]);
     query_params.open_access_pdf(true);
     query_params.min_citation_count(1);
-    query_params.publication_date_or_year("2020-01-01:");
+    query_params.publication_date_or_year("2020-01-01:".parse().unwrap());
     query_params.fields_of_study(vec![FieldsOfStudy::ComputerScience]);
 
-    let max_retry_count = 5;
-    let wait_time = 10;
     // Execute
-    let res = ss
-        .query_papers_by_title(query_params, max_retry_count, wait_time)
-        .await
-        .unwrap();
+    let res = ss.query_papers_by_title(query_params).await.unwrap();
 
     // Verify
     assert!(res.len() > 1);
@@ -168,16 +161,10 @@ async fn test_query_papers_4() {
         PaperField::S2FieldsOfStudy,
         PaperField::PublicationTypes,
         PaperField::CitationStyles,
-        PaperField::Embedding,
     ]);
 
-    let max_retry_count = 5;
-    let wait_time = 10;
     // Execute
-    let res = ss
-        .query_papers_by_title(query_params, max_retry_count, wait_time)
-        .await
-        .unwrap();
+    let res = ss.query_papers_by_title(query_params).await.unwrap();
 
     // Verify
     assert!(res.len() > 1);
@@ -192,13 +179,8 @@ async fn test_a_query_paper_1() {
     let mut ss = SemanticScholar::new();
     let mut query_params = QueryParams::default();
     query_params.query_text("attention is all you need");
-    let max_retry_count = 5;
-    let wait_time = 10;
     // Execute
-    let paper = ss
-        .query_a_paper_by_title(query_params, max_retry_count, wait_time)
-        .await
-        .unwrap();
+    let paper = ss.query_a_paper_by_title(query_params).await.unwrap();
 
     // Verify
     assert_eq!(
@@ -248,13 +230,8 @@ async fn test_a_query_paper_2() {
             PaperField::PublicationDate,
         ]),
     ]);
-    let max_retry_count = 5;
-    let wait_time = 10;
     // Execute
-    let paper = ss
-        .query_a_paper_by_title(query_params, max_retry_count, wait_time)
-        .await
-        .unwrap();
+    let paper = ss.query_a_paper_by_title(query_params).await.unwrap();
 
     println!("{:?}", paper.authors);
 
@@ -307,13 +284,8 @@ async fn test_a_query_paper_3() {
         ]),
     ]);
 
-    let max_retry_count = 5;
-    let wait_time = 10;
     // Execute
-    let paper = ss
-        .query_a_paper_by_title(query_params, max_retry_count, wait_time)
-        .await
-        .unwrap();
+    let paper = ss.query_a_paper_by_title(query_params).await.unwrap();
 
     println!("{:?}", paper.authors);
 
@@ -371,7 +343,7 @@ async fn test_query_paper_details() {
     ]);
 
     // Execute
-    let paper_details = ss.query_paper_details(query_params, 5, 10).await.unwrap();
+    let paper_details = ss.query_paper_details(query_params).await.unwrap();
 
     // Verify
     let title = if let Some(title) = paper_details.title.clone() {
@@ -386,6 +358,23 @@ async fn test_query_paper_details() {
     println!("{}", serde_json::to_string_pretty(&paper_details).unwrap());
 }
 
+#[tokio::test]
+#[serial]
+async fn test_query_paper_details_with_tldr() {
+    // Prepare
+    let mut ss = SemanticScholar::new();
+    let mut query_params = QueryParams::default();
+    query_params.paper_id("204e3073870fae3d05bcbc2f6a8e263d9b72e776");
+    query_params.fields(vec![PaperField::Title, PaperField::Tldr]);
+
+    // Execute
+    let paper_details = ss.query_paper_details(query_params).await.unwrap();
+
+    // Verify
+    let tldr = paper_details.tldr.expect("TLDR not found");
+    assert!(tldr.text.is_some());
+}
+
 #[tokio::test]
 #[serial]
 async fn test_query_paper_citations() {
@@ -403,7 +392,7 @@ async fn test_query_paper_citations() {
     ]);
 
     // Execute
-    let paper_citations = ss.query_paper_citations(query_params, 5, 10).await.unwrap();
+    let paper_citations = ss.query_paper_citations(query_params).await.unwrap();
 
     // Verify
     assert!(paper_citations.data.len() > 10);
@@ -425,10 +414,14 @@ async fn test_query_paper_references() {
     ]);
 
     // Execute
-    let paper_references = ss.query_paper_references(query_params, 5, 10).await.unwrap();
+    let paper_references = ss.query_paper_references(query_params).await.unwrap();
 
     // Verify
-    assert!(paper_references.data.len() > 0);
+    assert!(!paper_references.data.is_empty());
+    assert!(paper_references
+        .data
+        .iter()
+        .any(|reference| reference.cited_paper.is_some()));
     let json = serde_json::to_string_pretty(&paper_references).unwrap();
     println!("{}", json);
 }
@@ -454,7 +447,7 @@ async fn test_query_author_details() {
     ]);
 
     // Execute
-    let author = ss.query_author_details(query_params, 5, 10).await.unwrap();
+    let author = ss.query_author_details(query_params).await.unwrap();
 
     // Verify
     println!("{}", serde_json::to_string_pretty(&author).unwrap());
@@ -478,7 +471,7 @@ async fn test_search_authors() {
     ]);
 
     // Execute
-    let response = ss.search_authors(query_params, 5, 10).await.unwrap();
+    let response = ss.search_authors(query_params).await.unwrap();
 
     // Verify
     assert!(!response.data.is_empty());
@@ -502,7 +495,7 @@ async fn test_query_author_papers() {
     query_params.limit(10);
 
     // Execute
-    let response = ss.query_author_papers(query_params, 5, 10).await.unwrap();
+    let response = ss.query_author_papers(query_params).await.unwrap();
 
     // Verify
     assert!(!response.data.is_empty());
@@ -527,7 +520,7 @@ async fn test_query_paper_authors() {
     ]);
 
     // Execute
-    let response = ss.query_paper_authors(query_params, 5, 10).await.unwrap();
+    let response = ss.query_paper_authors(query_params).await.unwrap();
 
     // Verify
     assert!(!response.data.is_empty());